@@ -0,0 +1,204 @@
+use runner::{
+    Context, InputRng, ParseError, PartResult, Solution, Variant, parse_token, rand::Rng,
+};
+
+/// The dial rotations
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: usize = 1;
+
+    const VARIANTS: &'static [Variant<Self>] =
+        &[Variant::<Self>::part2("clicks", |input, _ctx| {
+            part2_clicks(input)
+        })];
+
+    type Input = Vec<Rotation>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(|l| Rotation::parse(input, l)).collect()
+    }
+
+    fn part1(input: &Self::Input, _ctx: &Context) -> PartResult {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _ctx: &Context) -> PartResult {
+        part2(input)
+    }
+
+    /// `size` rotations, some of them going round the dial several times
+    fn generate(rng: &mut InputRng, size: usize) -> Option<String> {
+        let mut input = String::new();
+        for _ in 0..size {
+            let direction = if rng.random_bool(0.5) { 'L' } else { 'R' };
+            let distance = rng.random_range(1..=999);
+            input.push_str(&format!("{}{}\n", direction, distance));
+        }
+        Some(input)
+    }
+}
+
+fn part1(rotations: &[Rotation]) -> PartResult {
+    let mut dial_state = DialState::new();
+    let mut zero_occurrences = 0;
+
+    for rotation in rotations {
+        dial_state.apply_rotation(rotation);
+
+        if dial_state.point() == 0 {
+            zero_occurrences += 1;
+        }
+    }
+
+    Ok(zero_occurrences.into())
+}
+
+fn part2(rotations: &[Rotation]) -> PartResult {
+    let mut dial_state = DialState::new();
+    let mut passing_zero = 0_u64;
+
+    for rotation in rotations {
+        passing_zero += u64::from(dial_state.apply_rotation(rotation));
+
+        if dial_state.point() == 0 {
+            passing_zero += 1;
+        }
+    }
+
+    Ok(passing_zero.into())
+}
+
+/// Part 2 turning the dial one click at a time and counting every click
+/// that lands on zero, to check the arithmetic in `apply_rotation`
+fn part2_clicks(rotations: &[Rotation]) -> PartResult {
+    let mut point = DIAL_START;
+    let mut zero_clicks = 0_u64;
+
+    for rotation in rotations {
+        let step = match rotation.direction {
+            Direction::Left => -1,
+            Direction::Right => 1,
+        };
+        for _ in 0..rotation.distance {
+            point = (point + step).rem_euclid(MAX_DIAL);
+            if point == 0 {
+                zero_clicks += 1;
+            }
+        }
+    }
+
+    Ok(zero_clicks.into())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        let value = value.to_ascii_lowercase();
+        match value {
+            'l' => Ok(Direction::Left),
+            'r' => Ok(Direction::Right),
+            _ => Err(format!("Invalid direction \"{}\"", value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rotation {
+    pub direction: Direction,
+    /// Clicks to turn the dial by
+    pub distance: i16,
+}
+
+impl Rotation {
+    /// Parse a line such as `L68`
+    pub fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let (direction, distance) = line.split_at(line.chars().next().map_or(0, char::len_utf8));
+        let direction = direction
+            .chars()
+            .next()
+            .and_then(|c| Direction::try_from(c).ok())
+            .ok_or_else(|| ParseError::at(input, direction, "a direction \"L\" or \"R\""))?;
+
+        Ok(Self {
+            direction,
+            distance: parse_token(input, distance, "a distance")?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct DialState {
+    point: i16,
+}
+
+const MAX_DIAL: i16 = 100;
+const DIAL_START: i16 = 50;
+
+impl DialState {
+    fn new() -> Self {
+        Self { point: DIAL_START }
+    }
+
+    /// Apply the rotation to the dial and return the number of times
+    /// it crosses zero during this rotation, not including if it stops
+    /// at zero
+    fn apply_rotation(&mut self, r: &Rotation) -> u16 {
+        // Count the number of times the dial crosses zero
+
+        // Because we only want to check if we step to 0 and not away from zero
+        // we check if we start at zero and reject the reduced_distance crossing
+        let started_at_zero = self.point == 0;
+
+        // There is a number of zero crossings implicit in the distance
+        let implicit_crossings = (r.distance / MAX_DIAL).unsigned_abs();
+        let reduced_distance = r.distance % MAX_DIAL;
+
+        // With the reduced_distance the dial can only cross the zero zero or one more time
+        let (new_point, extra_crossing): (i16, u16) = match r.direction {
+            Direction::Left => (
+                self.point - r.distance,
+                (self.point < reduced_distance).into(),
+            ),
+            Direction::Right => (
+                self.point + r.distance,
+                (self.point + reduced_distance > MAX_DIAL).into(),
+            ),
+        };
+
+        self.point = new_point.rem_euclid(MAX_DIAL);
+
+        if started_at_zero {
+            // Whole turns from zero stop at zero on the last one, which is
+            // not a crossing
+            implicit_crossings - u16::from(reduced_distance == 0 && implicit_crossings > 0)
+        } else {
+            implicit_crossings + extra_crossing
+        }
+    }
+
+    fn point(&self) -> i16 {
+        self.point
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_zeroes_over_long_inputs() {
+        let input = runner::generate_input::<Day1>(1, 20000).unwrap();
+        let rotations = Day1::parse(&input).unwrap();
+        assert_eq!(part2(&rotations), part2_clicks(&rotations));
+    }
+}
@@ -0,0 +1,426 @@
+use runner::{
+    Context, InputRng, ParseError, PartResult, ReplCommand, Solution, parse_token, rand::Rng, repl,
+};
+
+/// Fewest button presses to configure the factory machines
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: usize = 10;
+
+    const COMMANDS: &'static [ReplCommand<Self>] = &[
+        ReplCommand::<Self>::new(
+            "machine",
+            "INDEX",
+            "The parsed machine at INDEX, from 0",
+            |input, _, args| machine_arg(input, args).map(repl::pretty),
+        ),
+        ReplCommand::<Self>::new(
+            "lights",
+            "INDEX",
+            "The fewest buttons that light up the machine at INDEX",
+            |input, ctx, args| {
+                let sequence = machine_arg(input, args)?.compute_shortest_light_sequence(ctx)?;
+                Ok(format!(
+                    "{} presses: {}",
+                    sequence.len(),
+                    repl::pretty(&sequence)
+                ))
+            },
+        ),
+    ];
+
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(|l| Machine::parse(input, l)).collect()
+    }
+
+    fn part1(input: &Self::Input, ctx: &Context) -> PartResult {
+        part1(input, ctx)
+    }
+
+    fn part2(input: &Self::Input, ctx: &Context) -> PartResult {
+        part2(input, ctx)
+    }
+
+    /// `size` machines of 3 to 10 lights, every light wired to at least one
+    /// button. The lights are reached by pressing up to three buttons once
+    /// and the joltages by pressing each button up to 20 times, one of them
+    /// at least once
+    fn generate(rng: &mut InputRng, size: usize) -> Option<String> {
+        let mut input = String::new();
+        for _ in 0..size {
+            let lights = rng.random_range(3..=10);
+            let mut buttons = vec![Vec::new(); rng.random_range(2..=lights + 3)];
+            for light in 0..lights {
+                let count = buttons.len();
+                buttons[rng.random_range(0..count)].push(light);
+                for button in buttons.iter_mut().filter(|_| rng.random_bool(0.3)) {
+                    button.push(light);
+                }
+            }
+            for button in buttons.iter_mut() {
+                button.dedup();
+                if button.is_empty() {
+                    button.push(rng.random_range(0..lights));
+                }
+            }
+
+            let mut required_lights = vec![false; lights];
+            for _ in 0..rng.random_range(1..=3) {
+                for light in &buttons[rng.random_range(0..buttons.len())] {
+                    required_lights[*light] = !required_lights[*light];
+                }
+            }
+            let mut joltages = vec![0; lights];
+            let pressed = rng.random_range(0..buttons.len());
+            for (i, button) in buttons.iter().enumerate() {
+                let presses = rng.random_range(if i == pressed { 1 } else { 0 }..=20);
+                for light in button {
+                    joltages[*light] += presses;
+                }
+            }
+
+            let lights = required_lights
+                .iter()
+                .map(|on| if *on { '#' } else { '.' })
+                .collect::<String>();
+            let buttons = buttons
+                .iter()
+                .map(|b| format!("({})", join(b)))
+                .collect::<Vec<_>>();
+            input.push_str(&format!(
+                "[{}] {} {{{}}}\n",
+                lights,
+                buttons.join(" "),
+                join(&joltages)
+            ));
+        }
+        Some(input)
+    }
+}
+
+fn machine_arg<'a>(input: &'a [Machine], args: &[&str]) -> Result<&'a Machine, String> {
+    let index: usize = repl::arg(args, 0, "a machine index")?;
+    input
+        .get(index)
+        .ok_or(format!("There are only {} machines", input.len()))
+}
+
+fn join(values: &[usize]) -> String {
+    values
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn part1(input: &[Machine], ctx: &Context) -> PartResult {
+    let mut sum_of_sequences = 0;
+    for machine in input {
+        let shortest_sequence = machine.compute_shortest_light_sequence(ctx)?;
+        sum_of_sequences += shortest_sequence.len();
+        runner::debug!(
+            "Shortest sequence for {:?} is {:?}",
+            machine.required_lights,
+            shortest_sequence
+        );
+    }
+    Ok(sum_of_sequences.into())
+}
+
+fn part2(input: &[Machine], ctx: &Context) -> PartResult {
+    let mut sum_of_sequences = 0;
+    for machine in input {
+        let shortest_sequence = machine.compute_shortest_joltage_sequence(ctx)?;
+        sum_of_sequences += shortest_sequence.len();
+        runner::debug!(
+            "Shortest sequence for {:?} is {:?}",
+            machine.joltages,
+            shortest_sequence.len()
+        );
+    }
+    Ok(sum_of_sequences.into())
+}
+
+#[derive(Debug)]
+pub struct Machine {
+    pub required_lights: Vec<bool>,
+    pub buttons: Vec<Button>,
+    pub joltages: Vec<u16>,
+}
+
+impl Machine {
+    /// Parse a line such as `[.##.] (3) (1,3) {3,5,4,7}`
+    pub fn parse(input: &str, s: &str) -> Result<Self, ParseError> {
+        let end = &s[s.len()..];
+        if !s.starts_with('[') {
+            return Err(ParseError::at(input, &s[..0], "\"[\" opening the lights"));
+        }
+        let light_end = s
+            .find(']')
+            .ok_or_else(|| ParseError::at(input, end, "\"]\" closing the lights"))?;
+        let buttons_end = s[light_end..]
+            .find('{')
+            .map(|i| light_end + i)
+            .ok_or_else(|| ParseError::at(input, end, "\"{\" opening the joltages"))?;
+        if !s.ends_with('}') {
+            return Err(ParseError::at(input, end, "\"}\" closing the joltages"));
+        }
+
+        let lighting = &s[1..light_end];
+        let buttons = &s[(light_end + 1)..buttons_end];
+        let joltages = &s[(buttons_end + 1)..(s.len() - 1)];
+
+        let required_lights: Vec<bool> = lighting
+            .char_indices()
+            .map(|(i, c)| match c {
+                '#' => Ok(true),
+                '.' => Ok(false),
+                _ => {
+                    let light = &lighting[i..i + c.len_utf8()];
+                    Err(ParseError::at(input, light, "a light \"#\" or \".\""))
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        let buttons = buttons
+            .split_whitespace()
+            .map(|b| Button::parse(input, b, required_lights.len()))
+            .collect::<Result<_, _>>()?;
+        if joltages.split(',').count() != required_lights.len() {
+            let expected = format!("{} joltages, one per light", required_lights.len());
+            return Err(ParseError::at(input, joltages, expected));
+        }
+        let joltages = joltages
+            .split(',')
+            .map(|v| parse_token(input, v, "a joltage"))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            required_lights,
+            buttons,
+            joltages,
+        })
+    }
+
+    /// The fewest buttons that together light up the required lights
+    pub fn compute_shortest_light_sequence(&self, ctx: &Context) -> Result<Vec<Button>, String> {
+        // Lets try the naive way
+        let mut step_states = vec![StepState::new(self.required_lights.len())];
+        if step_states[0].light_state == self.required_lights {
+            return Ok(Vec::new());
+        }
+
+        loop {
+            ctx.check_budget()?;
+            let mut new_step_states = Vec::new();
+            for step_state in step_states.drain(..) {
+                ctx.check_budget()?;
+                for i in 0..self.buttons.len() {
+                    let mut step_state = step_state.clone();
+                    step_state.light_state = self.apply_button(i, step_state.light_state);
+                    step_state.buttons_pressed.push(self.buttons[i].clone());
+                    if step_state.light_state == self.required_lights {
+                        return Ok(step_state.buttons_pressed);
+                    }
+                    new_step_states.push(step_state);
+                }
+            }
+            if new_step_states.is_empty() {
+                return Err("No button sequence reaches the target".to_string());
+            }
+            step_states = new_step_states;
+        }
+    }
+
+    /// The fewest button presses that raise the counters to the joltages
+    pub fn compute_shortest_joltage_sequence(&self, ctx: &Context) -> Result<Vec<Button>, String> {
+        let mut step_states = vec![StepStateJolt::new(self.required_lights.len())];
+        if step_states[0].jolt_state == self.joltages {
+            return Ok(Vec::new());
+        }
+
+        loop {
+            ctx.check_budget()?;
+            let mut new_step_states = Vec::new();
+            for step_state in step_states.drain(..) {
+                ctx.check_budget()?;
+                for i in 0..self.buttons.len() {
+                    let mut step_state = step_state.clone();
+                    step_state.jolt_state = self.apply_button_jolt(i, step_state.jolt_state);
+                    step_state.buttons_pressed.push(self.buttons[i].clone());
+                    if step_state.jolt_state == self.joltages {
+                        return Ok(step_state.buttons_pressed);
+                    }
+
+                    // Since we cannot reverse an increment, if we've blown over any joltages we can reject that subtree
+                    if !any_joltage_over(&step_state.jolt_state, &self.joltages) {
+                        new_step_states.push(step_state);
+                    } else {
+                        runner::trace!("Overjoltage on {:?}, pruning", step_state.jolt_state);
+                    }
+                }
+            }
+            if new_step_states.is_empty() {
+                return Err("No button sequence reaches the target".to_string());
+            }
+            step_states = new_step_states;
+        }
+    }
+
+    fn apply_button(&self, button_index: usize, mut light_state: Vec<bool>) -> Vec<bool> {
+        let button = &self.buttons[button_index];
+        for index in &button.switch_set {
+            let i = *index as usize;
+            light_state[i] = !light_state[i];
+        }
+        runner::trace!(
+            "Applied button {:?} -> {:?}",
+            button.switch_set,
+            light_state
+        );
+        light_state
+    }
+
+    fn apply_button_jolt(&self, button_index: usize, mut jolt_state: Vec<u16>) -> Vec<u16> {
+        let button = &self.buttons[button_index];
+        for index in &button.switch_set {
+            let i = *index as usize;
+            jolt_state[i] += 1;
+        }
+        runner::trace!("Applied button {:?} -> {:?}", button.switch_set, jolt_state);
+        jolt_state
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Button {
+    /// Lights the button toggles, or counters it raises
+    pub switch_set: Vec<u8>,
+}
+
+impl Button {
+    /// Parse a button of a machine with `light_count` lights, which it may
+    /// only wire to lights that exist
+    pub fn parse(input: &str, s: &str, light_count: usize) -> Result<Self, ParseError> {
+        // expecting (##,##,...)
+        let content = s
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .ok_or_else(|| ParseError::at(input, s, "a button such as \"(1,3)\""))?;
+        let expected = format!("a light index below {}", light_count);
+        Ok(Self {
+            switch_set: content
+                .split(',')
+                .map(|v| match parse_token::<u8>(input, v, &expected)? {
+                    light if (light as usize) < light_count => Ok(light),
+                    _ => Err(ParseError::at(input, v, &expected)),
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct StepState {
+    buttons_pressed: Vec<Button>,
+    light_state: Vec<bool>,
+}
+
+impl StepState {
+    fn new(light_count: usize) -> Self {
+        Self {
+            buttons_pressed: Vec::new(),
+            light_state: vec![false; light_count],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct StepStateJolt {
+    buttons_pressed: Vec<Button>,
+    jolt_state: Vec<u16>,
+}
+
+impl StepStateJolt {
+    fn new(light_count: usize) -> Self {
+        Self {
+            buttons_pressed: Vec::new(),
+            jolt_state: vec![0; light_count],
+        }
+    }
+}
+
+fn any_joltage_over(joltages: &[u16], target: &[u16]) -> bool {
+    for (j, t) in joltages.iter().zip(target.iter()) {
+        if j > t {
+            return true;
+        }
+    }
+    false
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(line: &str) -> String {
+        Machine::parse(line, line).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn rejects_buttons_and_joltages_for_missing_lights() {
+        assert!(Machine::parse("[.#] (0) (0,1) {1,2}", "[.#] (0) (0,1) {1,2}").is_ok());
+        assert!(parse_error("[.#] (0) (0,2) {1,2}").contains("expected a light index below 2"));
+        assert!(
+            parse_error("[.#] (0) (0,1) {1,2,3}").contains("expected 2 joltages, one per light")
+        );
+    }
+
+    #[test]
+    fn stops_when_no_sequence_reaches_the_target() {
+        let ctx = Context::default();
+        let unreachable = "No button sequence reaches the target".to_string();
+        let no_buttons = Machine::parse("[#] {1}", "[#] {1}").unwrap();
+        assert_eq!(
+            no_buttons
+                .compute_shortest_light_sequence(&ctx)
+                .unwrap_err(),
+            unreachable
+        );
+        assert_eq!(
+            no_buttons
+                .compute_shortest_joltage_sequence(&ctx)
+                .unwrap_err(),
+            unreachable
+        );
+
+        let overshoots = Machine::parse("[#] (0,0) {1}", "[#] (0,0) {1}").unwrap();
+        assert_eq!(
+            overshoots
+                .compute_shortest_joltage_sequence(&ctx)
+                .unwrap_err(),
+            unreachable
+        );
+
+        let lights_off = Machine::parse("[.] (0) {2}", "[.] (0) {2}").unwrap();
+        assert_eq!(
+            lights_off
+                .compute_shortest_light_sequence(&ctx)
+                .unwrap()
+                .len(),
+            0
+        );
+        let no_joltage = Machine::parse("[#] (0) {0}", "[#] (0) {0}").unwrap();
+        assert_eq!(
+            no_joltage
+                .compute_shortest_joltage_sequence(&ctx)
+                .unwrap()
+                .len(),
+            0
+        );
+    }
+}
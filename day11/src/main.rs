@@ -1,16 +1,3 @@
-use get_input::get_input;
-
 fn main() {
-    let input = get_input(11).unwrap();
-
-    part1();
-    part2();
+    runner::run::<day11::Day11>();
 }
-
-fn part1() {
-
-}
-
-fn part2() {
-    
-}
\ No newline at end of file
@@ -0,0 +1,24 @@
+use runner::{Context, ParseError, PartResult, Solution};
+
+/// Not solved yet
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: usize = 12;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &Self::Input, _ctx: &Context) -> PartResult {
+        Err("Not solved yet".to_string())
+    }
+
+    fn part2(_input: &Self::Input, _ctx: &Context) -> PartResult {
+        Err("Not solved yet".to_string())
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
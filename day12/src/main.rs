@@ -1,16 +1,3 @@
-use get_input::get_input;
-
 fn main() {
-    let input = get_input(9).unwrap();
-
-    part1();
-    part2();
-}
-
-fn part1() {
-
-}
-
-fn part2() {
-    
+    runner::run::<day12::Day12>();
 }
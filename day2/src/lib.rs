@@ -0,0 +1,218 @@
+use runner::{
+    Context, InputRng, ParseError, PartResult, Solution, Variant, parse, parse_token, rand::Rng,
+};
+use std::iter::repeat_n;
+
+/// Sums of invalid product IDs. The input keeps each range's IDs as written
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: usize = 2;
+
+    const VARIANTS: &'static [Variant<Self>] =
+        &[Variant::<Self>::part1("plain-scan", |input, _ctx| {
+            part1_plain_scan(input)
+        })];
+
+    type Input = Vec<(String, String)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        format_input(input)
+    }
+
+    fn part1(input: &Self::Input, _ctx: &Context) -> PartResult {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _ctx: &Context) -> PartResult {
+        part2(input)
+    }
+
+    /// About `size` disjoint ranges of IDs of up to ten digits. Every ID in a
+    /// range is checked so each one is kept to at most 100000 IDs
+    fn generate(rng: &mut InputRng, size: usize) -> Option<String> {
+        let mut ranges = (0..size)
+            .map(|_| {
+                let digits = rng.random_range(1..=10);
+                let start = rng.random_range(10_u64.pow(digits - 1)..10_u64.pow(digits));
+                (start, start + rng.random_range(0..=start.min(100_000)))
+            })
+            .collect::<Vec<_>>();
+        ranges.sort();
+
+        let mut disjoint: Vec<(u64, u64)> = Vec::new();
+        for (start, end) in ranges {
+            if disjoint.last().is_none_or(|last| last.1 < start) {
+                disjoint.push((start, end));
+            }
+        }
+
+        let ranges = disjoint
+            .iter()
+            .map(|(start, end)| format!("{}-{}", start, end))
+            .collect::<Vec<_>>();
+        Some(format!("{}\n", ranges.join(",")))
+    }
+}
+
+fn format_input(input: &str) -> Result<Vec<(String, String)>, ParseError> {
+    let mut output = Vec::new();
+    for pair in input.split(",") {
+        let (start, end) = parse::split_range(input, pair.trim())?;
+        // The codes are kept as strings but must still be valid numbers
+        for code in [start, end] {
+            parse_token::<u64>(input, code, "a product ID")?;
+        }
+        output.push((start.to_string(), end.to_string()));
+    }
+    Ok(output)
+}
+
+fn ceil_to_even_number_of_digits(input: &str) -> u64 {
+    let input_str_len = input.len();
+
+    if input_str_len.is_multiple_of(2) {
+        input.parse().unwrap()
+    } else {
+        let new_length = input_str_len as u32;
+        10_u64.pow(new_length)
+    }
+}
+
+fn floor_to_even_number_of_digits(input: &str) -> u64 {
+    let input_str_len = input.len();
+
+    if input.len() == 1 || input_str_len.is_multiple_of(2) {
+        input.parse().unwrap()
+    } else {
+        let new_length = input_str_len as u32 - 1;
+        repeat_n('9', new_length as usize)
+            .collect::<String>()
+            .parse()
+            .unwrap()
+    }
+}
+
+fn split_number(input: u64) -> (u64, u64) {
+    let input = input.to_string();
+    let split = input.len() / 2;
+    let first_half = &input[..split];
+    let second_half = &input[split..];
+    (first_half.parse().unwrap(), second_half.parse().unwrap())
+}
+
+fn part1(inputs: &[(String, String)]) -> PartResult {
+    let total: u64 = inputs
+        .iter()
+        .map(|(s, e)| total_of_mirror_codes(s, e))
+        .sum();
+    Ok(total.into())
+}
+
+fn part2(inputs: &[(String, String)]) -> PartResult {
+    let total: u64 = inputs
+        .iter()
+        .map(|(s, e)| total_of_repeat_codes(s, e))
+        .sum();
+    Ok(total.into())
+}
+
+/// Part 1 checking every ID in each range as written, without first
+/// narrowing the range to IDs with an even number of digits
+fn part1_plain_scan(inputs: &[(String, String)]) -> PartResult {
+    let mut total = 0;
+    for (start, end) in inputs {
+        let start: u64 = start.parse().map_err(|_| "Invalid start")?;
+        let end: u64 = end.parse().map_err(|_| "Invalid end")?;
+        for id in start..=end {
+            let id_str = id.to_string();
+            let (left, right) = id_str.split_at(id_str.len() / 2);
+            if left == right {
+                total += id;
+            }
+        }
+    }
+    Ok(total.into())
+}
+
+/// Sum of the IDs in the range made of one sequence of digits twice
+pub fn total_of_mirror_codes(start_code: &str, end_code: &str) -> u64 {
+    let start = ceil_to_even_number_of_digits(start_code);
+    let end = floor_to_even_number_of_digits(end_code);
+
+    // Now we can begin by auto rejecting any range that is not ordered
+    if start > end {
+        return 0;
+    }
+
+    let mut count = 0;
+    for i in start..=end {
+        if code_is_mirror(i) {
+            count += i;
+        }
+    }
+
+    count
+}
+
+/// Whether the ID is one sequence of digits repeated twice, such as `6464`
+pub fn code_is_mirror(i: u64) -> bool {
+    let (left, right) = split_number(i);
+    left == right
+}
+
+/// Sum of the IDs in the range made of one sequence of digits repeated
+pub fn total_of_repeat_codes(start_code: &str, end_code: &str) -> u64 {
+    let start: u64 = start_code.parse().unwrap();
+    let end: u64 = end_code.parse().unwrap();
+
+    let mut count = 0;
+    for i in start..=end {
+        if code_is_repeated(i) {
+            count += i;
+        }
+    }
+
+    count
+}
+
+/// Whether the ID is one sequence of digits repeated at least twice, such
+/// as `123123123`
+pub fn code_is_repeated(i: u64) -> bool {
+    let code = i.to_string();
+
+    // So this iteration isn't over the string its over lengths of substrings
+    // The highest we can go is half of the length rounded down since more than that
+    // and we don't have enough length to reproduce the beginning substring
+    runner::trace!("Checking code {}", i);
+    let pattern_length_limit = code.len() / 2;
+    for i in (1..=pattern_length_limit).rev() {
+        // We reverse to early exit on larger patterns
+
+        // We can early reject the pattern if the code length isn't a multiple of the pattern size
+        if !code.len().is_multiple_of(i) {
+            continue;
+        }
+        // capture the potential pattern
+        let pattern_candidate = &code[..i];
+
+        // Step over the string starting at the end of the pattern with step size of the pattern
+        let mut failed = false;
+        let substring_start_endpoint = code.len() - i;
+        for j in (i..=substring_start_endpoint).step_by(i) {
+            if &code[j..(j + i)] != pattern_candidate {
+                failed = true;
+                break;
+            }
+        }
+
+        if !failed {
+            runner::debug!("{} is repeated", code);
+            return true;
+        }
+    }
+
+    false
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
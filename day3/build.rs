@@ -0,0 +1,3 @@
+fn main() {
+    fixtures::generate_example_tests(3, "Day3");
+}
@@ -0,0 +1,153 @@
+use iter_first_max::IterFirstMaxExt;
+use runner::{Context, InputRng, Param, ParseError, PartResult, Solution, rand::Rng};
+
+/// The most digits a joltage can have while still fitting a `u64`
+const MAX_DIGITS: u32 = 19;
+
+/// Battery bank joltages. The input keeps each bank's digits as written
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: usize = 3;
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("part1_digits", 2).between(1, MAX_DIGITS as i64),
+        Param::new("part2_digits", 12).between(1, MAX_DIGITS as i64),
+    ];
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        format_input(input)
+    }
+
+    fn part1(input: &Self::Input, ctx: &Context) -> PartResult {
+        part1(input, ctx.param("part1_digits")?)
+    }
+
+    fn part2(input: &Self::Input, ctx: &Context) -> PartResult {
+        part2(input, ctx.param("part2_digits")?)
+    }
+
+    /// `size` banks of 15 to 100 batteries, enough for the default number of
+    /// digits in both parts
+    fn generate(rng: &mut InputRng, size: usize) -> Option<String> {
+        let mut input = String::new();
+        for _ in 0..size {
+            let batteries = rng.random_range(15..=100);
+            input.extend((0..batteries).map(|_| char::from(b'0' + rng.random_range(1..=9))));
+            input.push('\n');
+        }
+        Some(input)
+    }
+}
+
+fn format_input(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut banks = Vec::new();
+    for line in input.lines() {
+        if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            let battery = &line[i..i + c.len_utf8()];
+            return Err(ParseError::at(input, battery, "a battery joltage digit"));
+        }
+        banks.push(line.to_string());
+    }
+    Ok(banks)
+}
+
+fn part1(input: &[String], digits: u32) -> PartResult {
+    let mut sum = 0_u64;
+    for bank in input {
+        let joltage = bank_maximum_joltage(bank, digits)?;
+        sum = sum.checked_add(joltage).ok_or(JOLTAGE_OVERFLOW)?;
+    }
+
+    Ok(sum.into())
+}
+
+fn part2(input: &[String], digits: u32) -> PartResult {
+    let mut sum = 0_u64;
+
+    for bank in input {
+        let joltage = bank_maximum_joltage(bank, digits)?;
+        sum = sum.checked_add(joltage).ok_or(JOLTAGE_OVERFLOW)?;
+    }
+
+    Ok(sum.into())
+}
+
+const JOLTAGE_OVERFLOW: &str = "The total joltage does not fit a u64";
+
+/// The largest number made by turning on `digits` batteries of the bank,
+/// keeping their order. Fails unless the bank has at least that many
+/// batteries and the number fits a `u64`
+pub fn bank_maximum_joltage(bank: &str, digits: u32) -> Result<u64, String> {
+    if !(1..=MAX_DIGITS).contains(&digits) {
+        return Err(format!(
+            "Cannot turn on {} batteries, expected 1 to {}",
+            digits, MAX_DIGITS
+        ));
+    }
+    if bank.len() < digits as usize {
+        return Err(format!(
+            "Cannot turn on {} batteries of the {} in bank \"{}\"",
+            digits,
+            bank.len(),
+            bank
+        ));
+    }
+
+    let mut collected_digits = String::new();
+    let mut digits_remaining = digits as usize;
+    let mut search_start = 0;
+    while digits_remaining > 0 {
+        let search_range_end = bank.len() - (digits_remaining - 1);
+        let search_range = &bank[search_start..search_range_end];
+        let (index, max) = search_range
+            .chars()
+            .enumerate()
+            .first_max_by(|l, r| l.1.cmp(&r.1))
+            .unwrap();
+        collected_digits.push(max);
+        digits_remaining -= 1;
+        search_start += index + 1;
+    }
+
+    collected_digits
+        .parse()
+        .map_err(|e| format!("Joltage \"{}\" is not a number: {}", collected_digits, e))
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_digit_counts_the_bank_cannot_fill() {
+        assert_eq!(bank_maximum_joltage("818181911112111", 2), Ok(92));
+        assert_eq!(
+            bank_maximum_joltage("818", 0),
+            Err("Cannot turn on 0 batteries, expected 1 to 19".to_string())
+        );
+        assert_eq!(
+            bank_maximum_joltage(&"9".repeat(30), 20),
+            Err("Cannot turn on 20 batteries, expected 1 to 19".to_string())
+        );
+        assert_eq!(
+            bank_maximum_joltage("818", 4),
+            Err("Cannot turn on 4 batteries of the 3 in bank \"818\"".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_totals_that_overflow() {
+        let banks = vec!["9".repeat(19); 2];
+        assert_eq!(
+            part1(&banks[..1], 19),
+            Ok(9_999_999_999_999_999_999_u64.into())
+        );
+        assert_eq!(part1(&banks, 19), Err(JOLTAGE_OVERFLOW.to_string()));
+        assert_eq!(part2(&banks, 19), Err(JOLTAGE_OVERFLOW.to_string()));
+    }
+}
@@ -0,0 +1,101 @@
+use runner::{
+    Context, InputRng, ParseError, PartResult, Solution,
+    grid::{Grid, Point},
+    image::{self, Rgb},
+    rand::Rng,
+};
+
+/// Paper rolls a forklift can reach. The input grid is true where a roll is
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: usize = 4;
+
+    type Input = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, input, char_to_boolean, "\".\" or \"@\"")
+    }
+
+    fn part1(input: &Self::Input, ctx: &Context) -> PartResult {
+        part1(input, ctx)
+    }
+
+    fn part2(input: &Self::Input, ctx: &Context) -> PartResult {
+        part2(input, ctx)
+    }
+
+    /// A `size` by `size` grid, about six in ten places holding a roll
+    fn generate(rng: &mut InputRng, size: usize) -> Option<String> {
+        let size = size.max(1);
+        let mut input = String::new();
+        for _ in 0..size {
+            input.extend((0..size).map(|_| if rng.random_bool(0.6) { '@' } else { '.' }));
+            input.push('\n');
+        }
+        Some(input)
+    }
+}
+
+fn part1(input: &Grid<bool>, ctx: &Context) -> PartResult {
+    let reachable = get_reachable_coordinates(input);
+    ctx.record(|| frame(input, &reachable));
+
+    Ok(reachable.len().into())
+}
+
+fn part2(input: &Grid<bool>, ctx: &Context) -> PartResult {
+    let mut input = input.clone();
+    let mut count = 0;
+
+    loop {
+        let reachable = get_reachable_coordinates(&input);
+        ctx.record(|| frame(&input, &reachable));
+        count += reachable.len();
+        if reachable.is_empty() {
+            break;
+        }
+
+        for p in reachable {
+            input[p] = false;
+        }
+    }
+
+    Ok(count.into())
+}
+
+/// The rolls in grey, with the reachable ones about to be removed in red
+fn frame(grid: &Grid<bool>, reachable: &[Point]) -> Grid<Rgb> {
+    let mut frame = grid.map(|_, roll| if *roll { image::GREY } else { image::BLACK });
+    for p in reachable {
+        frame[*p] = image::RED;
+    }
+    frame
+}
+
+fn char_to_boolean(c: char) -> Option<bool> {
+    match c {
+        '.' => Some(false),
+        '@' => Some(true),
+        _ => None,
+    }
+}
+
+fn coordinate_reachable(p: Point, grid: &Grid<bool>) -> bool {
+    if !grid[p] {
+        return false;
+    }
+
+    let occupied_neighbors = grid.adjacent(p).filter(|n| grid[*n]).count();
+
+    occupied_neighbors < 4
+}
+
+/// The rolls with fewer than four rolls next to them
+pub fn get_reachable_coordinates(grid: &Grid<bool>) -> Vec<Point> {
+    grid.points()
+        .filter(|p| coordinate_reachable(*p, grid))
+        .collect()
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
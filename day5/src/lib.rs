@@ -0,0 +1,220 @@
+use runner::{
+    Context, InputRng, ParseError, PartResult, ReplCommand, Solution, parse, parse_token,
+    rand::Rng, repl,
+};
+use std::ops::RangeInclusive;
+
+/// The inventory database
+pub struct Input {
+    /// Fresh ingredient ID ranges, sorted with overlapping and neighbouring
+    /// ranges merged
+    pub ranges: Vec<RangeInclusive<u64>>,
+    /// Available ingredient IDs
+    pub ingredients: Vec<u64>,
+}
+
+/// Fresh ingredients
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: usize = 5;
+
+    const COMMANDS: &'static [ReplCommand<Self>] = &[
+        ReplCommand::<Self>::new(
+            "contains",
+            "ID...",
+            "Whether each ingredient ID falls in a fresh range",
+            |input, _, args| {
+                if args.is_empty() {
+                    return Err("Expected an ingredient ID".to_string());
+                }
+                (0..args.len())
+                    .map(|i| {
+                        let id: u64 = repl::arg(args, i, "an ingredient ID")?;
+                        let fresh = if input.contains(id) {
+                            "fresh"
+                        } else {
+                            "spoiled"
+                        };
+                        Ok(format!("{}: {}\n", id, fresh))
+                    })
+                    .collect()
+            },
+        ),
+        ReplCommand::<Self>::new(
+            "ranges",
+            "",
+            "The fresh ranges after merging overlaps",
+            |input, _, _| Ok(repl::pretty(&input.ranges)),
+        ),
+    ];
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Input::parse(input)
+    }
+
+    fn part1(input: &Self::Input, _ctx: &Context) -> PartResult {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _ctx: &Context) -> PartResult {
+        part2(input)
+    }
+
+    /// `size` fresh ranges, often overlapping, then `size` ingredient IDs of
+    /// which about half fall in a range
+    fn generate(rng: &mut InputRng, size: usize) -> Option<String> {
+        let size = size.max(1);
+        let ranges = (0..size)
+            .map(|_| {
+                let start = rng.random_range(1..1_000_000_000_000_000_u64);
+                start..=start + rng.random_range(0..10_000_000_000_000)
+            })
+            .collect::<Vec<_>>();
+
+        let mut input = String::new();
+        for range in &ranges {
+            input.push_str(&format!("{}-{}\n", range.start(), range.end()));
+        }
+        input.push('\n');
+        for _ in 0..size {
+            let id = if rng.random_bool(0.5) {
+                let range = ranges[rng.random_range(0..size)].clone();
+                rng.random_range(range)
+            } else {
+                rng.random_range(1..1_000_000_000_000_000)
+            };
+            input.push_str(&format!("{}\n", id));
+        }
+        Some(input)
+    }
+}
+
+impl Input {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let [ranges, ingredients] = parse::sections(input)?;
+        let ingredients = ingredients
+            .lines()
+            .map(|l| parse_token(input, l, "an ingredient ID"))
+            .collect::<Result<_, _>>()?;
+
+        let mut original_ranges = Vec::new();
+        let mut endpoints = Vec::new();
+        for line in ranges.lines() {
+            let range = parse::range(input, line, "an ingredient ID")?;
+            let (start, end) = range.into_inner();
+            original_ranges.push(start..=end);
+            endpoints.push(RangeEndpoint::Start(start));
+            endpoints.push(RangeEndpoint::End(end));
+        }
+        endpoints.sort();
+
+        let mut compressed_endpoints = Vec::new();
+        let mut range_depth = 0;
+        let mut current_range_start = None;
+        for endpoint in &endpoints {
+            match endpoint {
+                RangeEndpoint::Start(s) => {
+                    if range_depth == 0 {
+                        current_range_start = Some(s);
+                    }
+                    range_depth += 1;
+                }
+                RangeEndpoint::End(e) => {
+                    range_depth -= 1;
+                    if let Some(start) = current_range_start
+                        && range_depth == 0
+                    {
+                        compressed_endpoints.push(*start..=*e);
+                    }
+                }
+            }
+        }
+
+        // Ranges can still be neighbors and 1 length ranges can overlap with neighbors
+        let compressed_endpoints = fuse_neighboring_ranges(&compressed_endpoints);
+
+        Ok(Self {
+            ranges: compressed_endpoints,
+            ingredients,
+        })
+    }
+
+    /// Whether the ingredient ID is fresh
+    pub fn contains(&self, v: u64) -> bool {
+        !self.ranges.iter().all(|r| !r.contains(&v))
+    }
+}
+
+fn part1(input: &Input) -> PartResult {
+    let mut count = 0;
+    for ingredient in &input.ingredients {
+        if input.contains(*ingredient) {
+            count += 1;
+        }
+    }
+    Ok(count.into())
+}
+
+fn part2(input: &Input) -> PartResult {
+    for r in &input.ranges {
+        runner::debug!("{:?}: {}", r, r.clone().count());
+    }
+    let s: usize = input.ranges.iter().cloned().map(|r| r.count()).sum();
+    Ok(s.into())
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum RangeEndpoint {
+    Start(u64),
+    End(u64),
+}
+
+impl Ord for RangeEndpoint {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let l = match self {
+            Self::Start(s) => s,
+            Self::End(e) => e,
+        };
+
+        let r = match other {
+            Self::Start(s) => s,
+            Self::End(e) => e,
+        };
+
+        l.cmp(r)
+    }
+}
+
+impl PartialOrd for RangeEndpoint {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Merge sorted, non-overlapping ranges that touch or are next to each other
+pub fn fuse_neighboring_ranges(ranges: &[RangeInclusive<u64>]) -> Vec<RangeInclusive<u64>> {
+    let mut fused = Vec::new();
+    let mut i = 0;
+    while i < ranges.len() {
+        let c = &ranges[i];
+        let current_start = *c.start();
+        let mut current_end = *c.end();
+        for (j, n) in ranges.iter().enumerate().skip(i + 1) {
+            if !(c.end() == n.start() || c.end() + 1 == *n.start()) {
+                i = j - 1;
+                break;
+            }
+            current_end = *ranges[j].end();
+            i = j;
+        }
+        fused.push(current_start..=current_end);
+        i += 1;
+    }
+
+    fused
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
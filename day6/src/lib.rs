@@ -0,0 +1,165 @@
+use runner::{Context, InputRng, ParseError, PartResult, Solution, parse, parse_token, rand::Rng};
+
+/// Cephalopod math homework
+pub struct Day6;
+
+/// The worksheet read row-wise for part 1 and column-wise for part 2
+pub struct Worksheet {
+    pub row_problems: Vec<Problem>,
+    pub column_problems: Vec<Problem>,
+}
+
+impl Solution for Day6 {
+    const DAY: usize = 6;
+
+    type Input = Worksheet;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // Input is any number of lines of numbers
+        // plus a line of operators
+        let lines = input.lines().collect::<Vec<_>>();
+        if lines.len() < 2 {
+            return Err(ParseError::new(
+                "Expected rows of numbers followed by a row of operators",
+            ));
+        }
+
+        let mut row_problems = Vec::new();
+        let mut column_problems = Vec::new();
+        for problem in parse::aligned_columns(&lines) {
+            let (op, rows) = problem.split_last().ok_or("Input is empty")?;
+            let operation = parse_operation(input, op.trim())?;
+            row_problems.push(Problem {
+                values: row_values(input, rows)?,
+                operation,
+            });
+            column_problems.push(Problem {
+                values: column_values(input, rows)?,
+                operation,
+            });
+        }
+
+        Ok(Worksheet {
+            row_problems,
+            column_problems,
+        })
+    }
+
+    fn part1(input: &Self::Input, _ctx: &Context) -> PartResult {
+        part1(&input.row_problems)
+    }
+
+    fn part2(input: &Self::Input, _ctx: &Context) -> PartResult {
+        part2(&input.column_problems)
+    }
+
+    /// `size` problems over two to four rows of numbers, each problem as wide
+    /// as its longest number with the others aligned to one side
+    fn generate(rng: &mut InputRng, size: usize) -> Option<String> {
+        let rows = rng.random_range(2..=4);
+        let mut lines = vec![String::new(); rows + 1];
+        for problem in 0..size.max(1) {
+            if problem > 0 {
+                lines.iter_mut().for_each(|l| l.push(' '));
+            }
+
+            let width = rng.random_range(1..=4);
+            let widest = rng.random_range(0..rows);
+            let align_left = rng.random_bool(0.5);
+            for (row, line) in lines[..rows].iter_mut().enumerate() {
+                let digits = if row == widest {
+                    width
+                } else {
+                    rng.random_range(1..=width)
+                };
+                let value = rng.random_range(10_u64.pow(digits - 1)..10_u64.pow(digits));
+                if align_left {
+                    line.push_str(&format!("{:<width$}", value, width = width as usize));
+                } else {
+                    line.push_str(&format!("{:>width$}", value, width = width as usize));
+                }
+            }
+
+            let operator = if rng.random_bool(0.5) { "+" } else { "*" };
+            lines[rows].push_str(&format!("{:<width$}", operator, width = width as usize));
+        }
+        Some(lines.join("\n") + "\n")
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Multiply,
+}
+
+impl TryFrom<&str> for Operation {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "*" => Ok(Operation::Multiply),
+            "+" => Ok(Operation::Add),
+            s => Err(format!("Unexpected character {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    pub values: Vec<u64>,
+    pub operation: Operation,
+}
+
+impl Problem {
+    pub fn solve(&self) -> u64 {
+        match self.operation {
+            Operation::Add => self.values.iter().sum(),
+            Operation::Multiply => self.values.iter().product(),
+        }
+    }
+}
+
+fn parse_operation(input: &str, op: &str) -> Result<Operation, ParseError> {
+    op.try_into()
+        .map_err(|_| ParseError::at(input, op, "an operator \"+\" or \"*\""))
+}
+
+/// Each row of a problem holds one number
+fn row_values(input: &str, rows: &[&str]) -> Result<Vec<u64>, ParseError> {
+    rows.iter()
+        .map(|r| parse_token(input, r.trim(), "a number"))
+        .collect()
+}
+
+/// Each column of a problem holds one number read top to bottom, with the
+/// columns taken right to left. Rows that end early are treated as blank
+fn column_values(input: &str, rows: &[&str]) -> Result<Vec<u64>, ParseError> {
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let mut values = Vec::new();
+    for col in (0..width).rev() {
+        let digits = rows
+            .iter()
+            .filter_map(|r| r.as_bytes().get(col))
+            .filter(|c| !c.is_ascii_whitespace())
+            .map(|c| *c as char)
+            .collect::<String>();
+        let value = digits
+            .parse()
+            .map_err(|_| ParseError::at(input, rows[0], "a number in every column"))?;
+        values.push(value);
+    }
+    Ok(values)
+}
+
+fn part1(input: &[Problem]) -> PartResult {
+    let total: u64 = input.iter().map(|p| p.solve()).sum();
+    Ok(total.into())
+}
+
+fn part2(input: &[Problem]) -> PartResult {
+    let total: u64 = input.iter().map(|p| p.solve()).sum();
+    Ok(total.into())
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
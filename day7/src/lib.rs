@@ -0,0 +1,309 @@
+use runner::{
+    Context, InputRng, ParseError, PartResult, ReplCommand, Solution, Variant,
+    grid::{Cell, Grid, Point},
+    image::{self, Rgb},
+    rand::Rng,
+    repl,
+};
+use std::collections::{HashMap, HashSet, hash_map::Keys};
+
+/// Tachyon beams through the manifold. The input is the manifold and the
+/// start
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: usize = 7;
+
+    const VARIANTS: &'static [Variant<Self>] = &[Variant::<Self>::part1(
+        "board2-splits",
+        |(grid, start), _ctx| part1_board2_splits(Board2::new(grid.clone(), *start)),
+    )];
+
+    const COMMANDS: &'static [ReplCommand<Self>] = &[ReplCommand::<Self>::new(
+        "step",
+        "COUNT",
+        "The beams after stepping the part 1 board COUNT times",
+        |(grid, start), _, args| {
+            let count: usize = repl::arg(args, 0, "a step count")?;
+            let mut board = Board::new(grid.clone(), *start);
+            let mut splits = 0;
+            for _ in 0..count {
+                splits += board.step();
+            }
+            Ok(format!(
+                "{} beams, {} splits\n{}",
+                board.lasers.len(),
+                splits,
+                board.render()
+            ))
+        },
+    )];
+
+    type Input = (Grid<Space>, Point);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid::parse(input, input, |c| c.try_into().ok(), "\".\", \"S\" or \"^\"")?;
+
+        let first_line = input.lines().next().ok_or("Input is empty")?;
+        let start = first_line
+            .find('S')
+            .ok_or_else(|| ParseError::at(input, first_line, "a start position \"S\""))?;
+
+        let start = grid
+            .point(Cell::new(start, 0))
+            .ok_or("The start is off the grid")?;
+        Ok((grid, start))
+    }
+
+    fn part1((grid, start): &Self::Input, ctx: &Context) -> PartResult {
+        part1(Board::new(grid.clone(), *start), ctx)
+    }
+
+    fn part2((grid, start): &Self::Input, _ctx: &Context) -> PartResult {
+        part2(Board2::new(grid.clone(), *start))
+    }
+
+    /// A manifold about `size` wide with the start in the middle of the top
+    /// row. Like the real input, splitters sit on every other row in a
+    /// widening triangle below the start, where the beams can reach them
+    fn generate(rng: &mut InputRng, size: usize) -> Option<String> {
+        let width = size.max(3) | 1;
+        let start = width / 2;
+
+        let mut input = String::new();
+        for row in 0..=width {
+            let half = row / 2;
+            for column in 0..width {
+                let offset = column.abs_diff(start);
+                let splitter = row % 2 == 0
+                    && offset < half
+                    && (offset + half) % 2 == 1
+                    && (1..width - 1).contains(&column)
+                    && rng.random_bool(0.8);
+                input.push(match (row, column) {
+                    (0, c) if c == start => 'S',
+                    _ if splitter => '^',
+                    _ => '.',
+                });
+            }
+            input.push('\n');
+        }
+        Some(input)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Space {
+    Blank,
+    Splitter,
+}
+
+impl TryFrom<char> for Space {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' | 'S' => Ok(Space::Blank),
+            '^' => Ok(Space::Splitter),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Part 1's beams, merged where they meet
+#[derive(Debug, Clone)]
+pub struct Board {
+    grid: Grid<Space>,
+    lasers: Vec<Point>,
+    laser_deduplicator: HashSet<Point>,
+}
+
+impl Board {
+    pub fn new(grid: Grid<Space>, start: Point) -> Self {
+        let mut laser_deduplicator = HashSet::new();
+        laser_deduplicator.insert(start);
+        Self {
+            grid,
+            lasers: vec![start],
+            laser_deduplicator,
+        }
+    }
+
+    /// Move every beam down a row, returning how many were split
+    pub fn step(&mut self) -> u64 {
+        let mut split_count = 0;
+        let mut new_lasers = Vec::new();
+        for laser in &self.lasers {
+            let new_coord = *laser + Point::DOWN;
+
+            // Check if laser is out of bounds then we can remove it from the pool
+            let Some(space) = self.grid.get(new_coord) else {
+                continue;
+            };
+
+            if self.laser_deduplicator.contains(&new_coord) {
+                continue;
+            } else {
+                self.laser_deduplicator.insert(new_coord);
+            }
+
+            match space {
+                Space::Blank => new_lasers.push(new_coord),
+                Space::Splitter => {
+                    split_count += 1;
+                    new_lasers.push(new_coord + Point::LEFT);
+                    new_lasers.push(new_coord + Point::RIGHT);
+                }
+            }
+        }
+        self.lasers = new_lasers;
+        split_count
+    }
+
+    /// Whether every beam has left the manifold
+    pub fn is_empty(&self) -> bool {
+        self.lasers.is_empty()
+    }
+
+    /// Draw the manifold as an image, the same as [`Board::render`] with the
+    /// beams in green and their ends in yellow
+    pub fn frame(&self) -> Grid<Rgb> {
+        let mut frame = self.grid.map(|_, space| match space {
+            Space::Splitter => image::WHITE,
+            Space::Blank => image::BLACK,
+        });
+        for p in &self.laser_deduplicator {
+            if self.grid[*p] == Space::Blank {
+                frame[*p] = image::GREEN;
+            }
+        }
+        for p in &self.lasers {
+            if let Some(Space::Blank) = self.grid.get(*p) {
+                frame[*p] = image::YELLOW;
+            }
+        }
+        frame
+    }
+
+    /// Draw the manifold with `|` where a beam has been and `*` where the
+    /// beams are now
+    pub fn render(&self) -> String {
+        self.grid.render(|coord, space| match space {
+            Space::Splitter => '^',
+            _ if self.lasers.contains(&coord) => '*',
+            _ if self.laser_deduplicator.contains(&coord) => '|',
+            Space::Blank => '.',
+        })
+    }
+}
+
+fn part1(mut board: Board, ctx: &Context) -> PartResult {
+    let mut total_splits = 0;
+    ctx.record(|| board.frame());
+    while !board.is_empty() {
+        total_splits += board.step();
+        ctx.record(|| board.frame());
+    }
+    Ok(total_splits.into())
+}
+
+/// Part 1 counting the splitters reached by part 2's board, to check the
+/// beam merging in `Board`
+fn part1_board2_splits(mut board: Board2) -> PartResult {
+    while board.step() {}
+
+    Ok(board.splits().into())
+}
+
+fn part2(mut board: Board2) -> PartResult {
+    while board.step() {}
+
+    Ok(board.laser_count().into())
+}
+
+/// Part 2's beams, counting the timelines that reach each position
+pub struct Board2 {
+    grid: Grid<Space>,
+    lasers: OverlapMap,
+    splits: u64,
+}
+
+impl Board2 {
+    pub fn new(grid: Grid<Space>, start: Point) -> Self {
+        let mut h = OverlapMap::new();
+        h.add(start, 1);
+        Self {
+            grid,
+            lasers: h,
+            splits: 0,
+        }
+    }
+
+    /// Move every beam down a row, returning false once they left the
+    /// bottom or all left the sides. Every beam is on the same row
+    pub fn step(&mut self) -> bool {
+        match self.lasers.keys().next() {
+            Some(laser) if usize::try_from(laser.y).is_ok_and(|y| y < self.grid.height()) => {}
+            _ => return false,
+        }
+
+        let mut new_lasers = OverlapMap::new();
+        for (coord, overlap_count) in self.lasers.map() {
+            let new_coord = *coord + Point::DOWN;
+            // Beams split off the side of the manifold are lost
+            let Some(space) = self.grid.get(*coord) else {
+                continue;
+            };
+            match space {
+                Space::Blank => new_lasers.add(new_coord, *overlap_count),
+                Space::Splitter => {
+                    self.splits += 1;
+                    new_lasers.add(new_coord + Point::LEFT, *overlap_count);
+                    new_lasers.add(new_coord + Point::RIGHT, *overlap_count);
+                }
+            }
+        }
+        self.lasers = new_lasers;
+
+        true
+    }
+
+    /// Timelines across every position
+    pub fn laser_count(&self) -> u64 {
+        self.lasers.map().values().sum()
+    }
+
+    /// Splitters reached by any timeline so far
+    pub fn splits(&self) -> u64 {
+        self.splits
+    }
+}
+
+#[derive(Debug)]
+struct OverlapMap {
+    m: HashMap<Point, u64>,
+}
+
+impl OverlapMap {
+    fn new() -> Self {
+        Self { m: HashMap::new() }
+    }
+
+    fn add(&mut self, coord: Point, value: u64) {
+        if let Some(entry) = self.m.get_mut(&coord) {
+            *entry += value;
+        } else {
+            self.m.insert(coord, value);
+        }
+    }
+
+    fn keys(&self) -> Keys<'_, Point, u64> {
+        self.m.keys()
+    }
+
+    fn map(&self) -> &HashMap<Point, u64> {
+        &self.m
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
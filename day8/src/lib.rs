@@ -0,0 +1,195 @@
+use runner::{Context, InputRng, Param, ParseError, PartResult, Solution, parse, rand::Rng};
+use std::{collections::HashSet, ops::Sub};
+
+/// Junction box circuits
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: usize = 8;
+
+    const PARAMS: &'static [Param] = &[Param::new("connections", 1000)];
+
+    type Input = Vec<Vec3>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(|l| Vec3::parse(input, l)).collect()
+    }
+
+    fn part1(input: &Self::Input, ctx: &Context) -> PartResult {
+        part1(input, ctx.param("connections")?)
+    }
+
+    fn part2(input: &Self::Input, _ctx: &Context) -> PartResult {
+        part2(input)
+    }
+
+    /// `size` distinct junction boxes with coordinates below 100000
+    fn generate(rng: &mut InputRng, size: usize) -> Option<String> {
+        let mut seen = HashSet::new();
+        let mut input = String::new();
+        while seen.len() < size {
+            let point = [(); 3].map(|_| rng.random_range(0..100_000));
+            if seen.insert(point) {
+                input.push_str(&format!("{},{},{}\n", point[0], point[1], point[2]));
+            }
+        }
+        Some(input)
+    }
+}
+
+fn part1(input: &[Vec3], take: usize) -> PartResult {
+    let distances = compute_sorted_distances(input);
+    let circuits = join_first_n_junctions(take, &distances);
+    let mut circuit_sizes = circuits.iter().map(|c| c.len() as u64).collect::<Vec<_>>();
+    circuit_sizes.sort_by(|l, r| r.cmp(l));
+    runner::debug!("Largest circuits: {:?}", circuit_sizes);
+    Ok(circuit_sizes
+        .iter()
+        .cloned()
+        .take(3)
+        .product::<u64>()
+        .into())
+}
+
+fn part2(input: &[Vec3]) -> PartResult {
+    let distances = compute_sorted_distances(input);
+    let (j1, j2) = join_junctions_until_one_circuit(&distances, input.len())?;
+    Ok((j1.x * j2.x).into())
+}
+
+/// Every pair of junction boxes with their squared distance, closest first
+pub fn compute_sorted_distances(input: &[Vec3]) -> Vec<(i64, &Vec3, &Vec3)> {
+    let mut distances = Vec::new();
+    for (i, entry) in input.iter().enumerate() {
+        for j in input.iter().skip(i + 1) {
+            distances.push(((*j - *entry).mag_sqrd(), entry, j));
+        }
+    }
+    distances.sort_unstable_by_key(|d| d.0);
+    distances
+}
+
+/// The circuits formed by connecting the `n` closest pairs, leaving out
+/// boxes that stay unconnected
+pub fn join_first_n_junctions(n: usize, distances: &[(i64, &Vec3, &Vec3)]) -> Vec<HashSet<Vec3>> {
+    let mut circuits = Vec::new();
+    for (_distance, j1, j2) in distances.iter().take(n) {
+        // Check that if one is a circuit that they both aren't in the same circuit
+        if get_circuit(&mut circuits, j1).is_some()
+            && get_circuit(&mut circuits, j1) == get_circuit(&mut circuits, j2)
+        {
+            runner::trace!("{:?} <-> {:?} are from the same circuit", j1, j2);
+            continue;
+        }
+
+        merge_junctions(&mut circuits, j1, j2);
+        runner::trace!("Joined {:?} <-> {:?}, circuits: {:?}", j1, j2, circuits);
+    }
+    circuits
+}
+
+fn join_junctions_until_one_circuit(
+    distances: &[(i64, &Vec3, &Vec3)],
+    junction_count: usize,
+) -> Result<(Vec3, Vec3), String> {
+    let mut circuits = Vec::new();
+    for (_distance, j1, j2) in distances.iter() {
+        // Check that if one is a circuit that they both aren't in the same circuit
+        if get_circuit(&mut circuits, j1).is_some()
+            && get_circuit(&mut circuits, j1) == get_circuit(&mut circuits, j2)
+        {
+            runner::trace!("{:?} <-> {:?} are from the same circuit", j1, j2);
+            continue;
+        }
+
+        merge_junctions(&mut circuits, j1, j2);
+        if let Some(circuit) = circuits.first()
+            && circuit.len() == junction_count
+        {
+            return Ok((**j1, **j2));
+        }
+    }
+
+    Err("The junction boxes never form a single circuit".to_string())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vec3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Vec3 {
+    /// Parse a line such as `162,817,812`
+    pub fn parse(input: &str, s: &str) -> Result<Self, ParseError> {
+        let [x, y, z] = parse::tuple(input, s, ',', "a coordinate")?;
+        Ok(Self { x, y, z })
+    }
+
+    pub fn mag_sqrd(&self) -> i64 {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Vec3;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vec3 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+fn get_circuit(circuits: &mut [HashSet<Vec3>], j: &Vec3) -> Option<usize> {
+    circuits.iter().enumerate().find_map(|(i, a)| {
+        if a.contains(j) {
+            return Some(i);
+        }
+        None
+    })
+}
+
+fn merge_junctions(circuits: &mut Vec<HashSet<Vec3>>, j1: &Vec3, j2: &Vec3) {
+    let j1_circuit = get_circuit(circuits, j1);
+    let j2_circuit = get_circuit(circuits, j2);
+
+    match (j1_circuit, j2_circuit) {
+        (Some(c1), Some(c2)) => {
+            let c = circuits[c2].clone();
+            circuits[c1].extend(c);
+            circuits.remove(c2);
+        }
+        (None, Some(c)) => {
+            circuits[c].insert(*j1);
+        }
+        (Some(c), None) => {
+            circuits[c].insert(*j2);
+        }
+        (None, None) => {
+            let mut new_circuit = HashSet::new();
+            new_circuit.insert(*j1);
+            new_circuit.insert(*j2);
+            circuits.push(new_circuit);
+        }
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fails_without_a_connection_to_make() {
+        let input = Day8::parse("162,817,812\n").unwrap();
+        assert_eq!(
+            part2(&input),
+            Err("The junction boxes never form a single circuit".to_string())
+        );
+    }
+}
@@ -0,0 +1,446 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::RangeInclusive,
+};
+
+use runner::{Context, InputRng, ParseError, PartResult, Solution, Variant, parse, rand::Rng};
+use serde::{Deserialize, Serialize};
+
+/// The largest rectangles between red tiles
+pub struct Day9;
+
+/// The red tiles in order around the loop, along with their bounding box
+pub struct Floor {
+    pub tiles: Vec<Vec2>,
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+impl Solution for Day9 {
+    const DAY: usize = 9;
+
+    const VARIANTS: &'static [Variant<Self>] =
+        &[Variant::<Self>::part2("point-in-polygon", |input, _ctx| {
+            part2_point_in_polygon(&input.tiles)
+        })];
+
+    type Input = Floor;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines = input.lines().collect::<Vec<_>>();
+        let tiles = lines
+            .iter()
+            .map(|l| Vec2::parse(input, l))
+            .collect::<Result<Vec<_>, _>>()?;
+        check_loop(input, &lines, &tiles)?;
+        let min_x = tiles.iter().map(|v| v.x).min().ok_or("Input is empty")?;
+        let min_y = tiles.iter().map(|v| v.y).min().ok_or("Input is empty")?;
+        let max_x = tiles.iter().map(|v| v.x).max().ok_or("Input is empty")?;
+        let max_y = tiles.iter().map(|v| v.y).max().ok_or("Input is empty")?;
+
+        Ok(Floor {
+            tiles,
+            min: Vec2::new(min_x, min_y),
+            max: Vec2::new(max_x, max_y),
+        })
+    }
+
+    fn part1(input: &Self::Input, _ctx: &Context) -> PartResult {
+        part1(&input.tiles)
+    }
+
+    fn part2(input: &Self::Input, ctx: &Context) -> PartResult {
+        part2(&input.tiles, &input.min, &input.max, ctx)
+    }
+
+    /// A loop of about `size` red tiles tracing the outline of a histogram:
+    /// bars of random width and height standing on a common base, so each
+    /// tile turns a corner and the loop never crosses itself. The loop runs
+    /// either way round from any of its tiles
+    fn generate(rng: &mut InputRng, size: usize) -> Option<String> {
+        let bars = (size / 2).max(2);
+        let base = rng.random_range(1..1000);
+        let mut x = rng.random_range(1..1000_u64);
+
+        let mut tiles = vec![(x, base)];
+        let mut previous_height = base;
+        for _ in 0..bars {
+            let height = loop {
+                let height = base + rng.random_range(1..=10 * bars as u64);
+                if height != previous_height {
+                    break height;
+                }
+            };
+            tiles.push((x, height));
+            x += rng.random_range(1..=1000);
+            tiles.push((x, height));
+            previous_height = height;
+        }
+        tiles.push((x, base));
+
+        // The loop may run either way round and start at any tile
+        if rng.random_bool(0.5) {
+            tiles.reverse();
+        }
+        let start = rng.random_range(0..tiles.len());
+        tiles.rotate_left(start);
+
+        Some(
+            tiles
+                .iter()
+                .map(|(x, y)| format!("{},{}\n", x, y))
+                .collect(),
+        )
+    }
+}
+
+fn part1(input: &[Vec2]) -> PartResult {
+    let areas = compute_areas(input);
+    Ok(areas.first().ok_or("No pairs of tiles")?.0.into())
+}
+
+fn part2(input: &[Vec2], min: &Vec2, max: &Vec2, ctx: &Context) -> PartResult {
+    let bounds = create_vertical_line_bounds(input);
+    runner::debug!("Computed bounds");
+    let compressed_bounds = ctx.artifact("compressed_row_bounds", 2, || {
+        runner::info!("Building compressed row bounds");
+        CompressedRowBounds::from_line_bounds(&bounds, min, max, ctx)
+    })?;
+    runner::debug!("Finished compression");
+
+    let areas = compute_areas(input);
+    let mut progress = ctx.progress("areas checked", areas.len() as u64);
+    for area in &areas {
+        ctx.check_budget()?;
+        progress.tick();
+        if points_in_bounds(&four_edges(area.1.0, area.1.1), &compressed_bounds) {
+            return Ok(area.0.into());
+        }
+    }
+    Err("No valid area".to_string())
+}
+
+/// Part 2 testing every tile on the edges of each rectangle against the loop
+/// directly, instead of through the compressed row bounds
+fn part2_point_in_polygon(input: &[Vec2]) -> PartResult {
+    for (area, (a, b)) in compute_areas(input) {
+        if four_edges(a, b).iter().all(|p| in_loop(input, p)) {
+            return Ok(area.into());
+        }
+    }
+    Err("No valid area".to_string())
+}
+
+/// Whether a tile is on the loop or inside it. Tiles off the loop are
+/// inside when a ray cast left from them crosses an odd number of its
+/// vertical edges, counting each edge from its top end up to its bottom
+pub fn in_loop(tiles: &[Vec2], p: &Vec2) -> bool {
+    let edges = tiles.iter().zip(tiles.iter().cycle().skip(1));
+
+    let mut inside = false;
+    for (a, b) in edges {
+        let (min_x, max_x) = (a.x.min(b.x), a.x.max(b.x));
+        let (min_y, max_y) = (a.y.min(b.y), a.y.max(b.y));
+        if (min_x..=max_x).contains(&p.x) && (min_y..=max_y).contains(&p.y) {
+            return true;
+        }
+        if a.x == b.x && a.x < p.x && (min_y..max_y).contains(&p.y) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Vec2 {
+    pub x: u64,
+    pub y: u64,
+}
+
+impl Vec2 {
+    pub fn new(x: u64, y: u64) -> Self {
+        Self { x, y }
+    }
+
+    /// Parse a line such as `7,1`
+    pub fn parse(input: &str, s: &str) -> Result<Self, ParseError> {
+        let [x, y] = parse::tuple(input, s, ',', "a coordinate")?;
+        Ok(Self { x, y })
+    }
+
+    /// Tiles in the rectangle with the two tiles as opposite corners
+    pub fn area_between(&self, rhs: &Vec2) -> u64 {
+        let x_span = self.x.abs_diff(rhs.x) + 1;
+        let y_span = self.y.abs_diff(rhs.y) + 1;
+
+        x_span * y_span
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CompressedRowBounds {
+    bounds: HashMap<u64, Vec<RangeInclusive<u64>>>,
+}
+
+impl CompressedRowBounds {
+    fn from_line_bounds(
+        bounds: &HashSet<Vec2>,
+        min: &Vec2,
+        max: &Vec2,
+        ctx: &Context,
+    ) -> Result<Self, String> {
+        let mut all_range_bounds = HashMap::new();
+        let mut progress = ctx.progress("rows compressed", max.y - min.y + 1);
+        for y in min.y..=max.y {
+            ctx.check_budget()?;
+            progress.tick();
+            let mut range_start = 0;
+            let mut row_bounds = Vec::new();
+            let mut in_bound = false;
+            for x in min.x..=max.x {
+                if bounds.contains(&Vec2::new(x, y)) {
+                    if in_bound {
+                        // Exiting a range
+                        row_bounds.push(range_start..=x);
+                    } else {
+                        // Entering a range
+                        range_start = x;
+                    }
+                    in_bound = !in_bound;
+                }
+            }
+            all_range_bounds.insert(y, row_bounds);
+        }
+
+        Ok(Self {
+            bounds: all_range_bounds,
+        })
+    }
+
+    /// Whether the point is inside the loop, rows outside the bounding box
+    /// having nothing inside
+    fn point_in_bounds(&self, p: &Vec2) -> bool {
+        self.bounds
+            .get(&p.y)
+            .is_some_and(|row| row.iter().any(|range| range.contains(&p.x)))
+    }
+}
+
+/// Check the tiles make a loop, each one sharing a row or a column with the
+/// next and the last one with the first
+fn check_loop(input: &str, lines: &[&str], tiles: &[Vec2]) -> Result<(), ParseError> {
+    if tiles.len() < 2 {
+        return Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "at least two red tiles",
+        ));
+    }
+
+    for (i, (a, b)) in tiles.iter().zip(tiles.iter().cycle().skip(1)).enumerate() {
+        if get_line_direction(a, b).is_none() {
+            let line = lines[(i + 1) % lines.len()];
+            let expected = if a == b {
+                "a tile other than the one before"
+            } else if i + 1 == tiles.len() {
+                "the first tile in the same row or column as the last, closing the loop"
+            } else {
+                "a tile in the same row or column as the one before"
+            };
+            return Err(ParseError::at(input, line, expected));
+        }
+    }
+    Ok(())
+}
+
+/// Every pair of tiles with the area of the rectangle between them, largest
+/// first
+pub fn compute_areas(input: &[Vec2]) -> Vec<(u64, (&Vec2, &Vec2))> {
+    let mut areas = Vec::new();
+    for (i, entry) in input.iter().enumerate() {
+        for j in input.iter().skip(i + 1) {
+            areas.push((entry.area_between(j), (entry, j)));
+        }
+    }
+    areas.sort_by_key(|a| std::cmp::Reverse(a.0));
+    areas
+}
+
+fn line_between(a: &Vec2, b: &Vec2) -> Vec<Vec2> {
+    if *a == *b {
+        return vec![*a];
+    }
+
+    match (a.x == b.x, a.y == b.y) {
+        (true, false) => {
+            if a.y < b.y {
+                (a.y..=b.y).map(|v| Vec2::new(a.x, v)).collect()
+            } else {
+                (b.y..=a.y).map(|v| Vec2::new(a.x, v)).collect()
+            }
+        } // Vertical line
+        (false, true) => {
+            if a.x < b.x {
+                (a.x..=b.x).map(|v| Vec2::new(v, a.y)).collect()
+            } else {
+                (b.x..=a.x).map(|v| Vec2::new(v, a.y)).collect()
+            }
+        }
+        (false, false) => unreachable!("{:?} and {:?} share no row or column", a, b),
+        (true, true) => unreachable!("equal points are handled above"),
+    }
+}
+
+/// Return the line of points between a and b only if they form a
+/// vertical line. This line does not include the endpoints
+fn line_between_vertical_only(
+    a: &Vec2,
+    b: &Vec2,
+    exclude_endpoint: bool,
+    exclude_start_point: bool,
+) -> Vec<Vec2> {
+    if *a == *b {
+        return vec![*a];
+    }
+
+    let (start, end) = match (a.x == b.x, a.y == b.y) {
+        (true, false) => {
+            if a.y < b.y {
+                let start = if exclude_start_point { a.y + 1 } else { a.y };
+
+                let end = if exclude_endpoint { b.y } else { b.y + 1 };
+
+                (start, end)
+            } else {
+                let end = if exclude_start_point { a.y } else { a.y + 1 };
+
+                let start = if exclude_endpoint { b.y + 1 } else { b.y };
+
+                (start, end)
+            }
+        }
+        (false, true) => return Vec::new(),
+        (false, false) => unreachable!("{:?} and {:?} share no row or column", a, b),
+        (true, true) => unreachable!("equal points are handled above"),
+    };
+
+    (start..end).map(|v| Vec2::new(a.x, v)).collect()
+}
+
+fn four_edges(a: &Vec2, b: &Vec2) -> Vec<Vec2> {
+    let other1 = Vec2::new(a.x, b.y);
+    let other2 = Vec2::new(b.x, a.y);
+
+    let mut lines = Vec::new();
+    lines.extend(line_between(a, &other1));
+    lines.extend(line_between(a, &other2));
+    lines.extend(line_between(b, &other1));
+    lines.extend(line_between(b, &other2));
+    lines
+}
+
+fn points_in_bounds(p: &[Vec2], bounds: &CompressedRowBounds) -> bool {
+    p.iter().all(|p| bounds.point_in_bounds(p))
+}
+
+fn create_vertical_line_bounds(points: &[Vec2]) -> HashSet<Vec2> {
+    // The corner rules below hold for a loop running clockwise from the
+    // start of a horizontal line, so put the points in that order first
+    let mut points = points.to_vec();
+    if !runs_clockwise(&points) {
+        points.reverse();
+    }
+    if points[0].x == points[1].x {
+        points.rotate_left(1);
+    }
+
+    let mut wrapped_points = points.clone();
+    wrapped_points.push(points[0]); // This closes the polygon
+    wrapped_points.push(points[1]); // This allows our corner direction algorithm to see the last corner direction
+    let mut bounds = HashSet::new();
+    let mut exclude_start_point = false;
+    for w in wrapped_points.windows(3) {
+        // Here we can use the third point to get directional information
+        // Parsing checked every neighbour shares a row or a column
+        let d1 = get_line_direction(&w[0], &w[1]).unwrap();
+        let d2 = get_line_direction(&w[1], &w[2]).unwrap();
+        let exclude_endpoint = matches!(
+            (d1, d2),
+            (LineDirection::Up, LineDirection::Left)
+                | (LineDirection::Right, LineDirection::Up)
+                | (LineDirection::Down, LineDirection::Right)
+                | (LineDirection::Left, LineDirection::Down)
+        );
+
+        bounds.extend(line_between_vertical_only(
+            &w[0],
+            &w[1],
+            exclude_endpoint,
+            exclude_start_point,
+        ));
+        exclude_start_point = exclude_endpoint;
+    }
+
+    bounds
+}
+
+/// Whether the loop runs clockwise as drawn, with y growing downwards, going
+/// by the sign of its area from the shoelace formula
+fn runs_clockwise(points: &[Vec2]) -> bool {
+    let edges = points.iter().zip(points.iter().cycle().skip(1));
+    let twice_area: i128 = edges
+        .map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128)
+        .sum();
+    twice_area > 0
+}
+
+#[derive(Debug, Clone, Copy)]
+enum LineDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+// Return the direction of the line or nothing if a and b are the same or
+// share no row or column
+fn get_line_direction(a: &Vec2, b: &Vec2) -> Option<LineDirection> {
+    match (a.x == b.x, a.y == b.y) {
+        (true, false) => {
+            // Vertical line
+            if a.y < b.y {
+                Some(LineDirection::Down)
+            } else {
+                Some(LineDirection::Up)
+            }
+        }
+        (false, true) => {
+            // Horizontal line
+            if a.x < b.x {
+                Some(LineDirection::Right)
+            } else {
+                Some(LineDirection::Left)
+            }
+        }
+        (false, false) | (true, true) => None,
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(input: &str) -> String {
+        Day9::parse(input).err().unwrap().to_string()
+    }
+
+    #[test]
+    fn rejects_tiles_that_do_not_make_a_loop() {
+        assert!(parse_error("7,1\n").contains("expected at least two red tiles"));
+        assert!(parse_error("7,1\n9,3\n9,1\n").contains("line 2"));
+        assert!(parse_error("7,1\n7,1\n").contains("other than the one before"));
+        assert!(parse_error("7,1\n11,1\n11,7\n").contains("closing the loop"));
+    }
+}
@@ -0,0 +1,102 @@
+use std::{fmt::Write, path::PathBuf};
+
+use crate::fixture::{examples_dir, fixture_names};
+
+const GENERATED_FILE: &str = "examples.rs";
+
+/// Generate an `examples` test module with one `#[test]` per example fixture
+/// of the day, plus tests of the day's input generator and its variants.
+/// Intended to be called from a day's build script with the path of the
+/// day's solution type, the generated module is then included at the crate
+/// root:
+///
+/// ```ignore
+/// include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+/// ```
+pub fn generate_example_tests(day: usize, solution: &str) {
+    let dir = examples_dir(day);
+    println!("cargo::rerun-if-changed={}", dir.display());
+
+    let names = match fixture_names(day) {
+        Ok(names) => names,
+        Err(err) => panic!("Could not list fixtures: {}", err),
+    };
+    let generated = example_tests(&names, solution);
+
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR is set by cargo for build scripts");
+    let out_path = PathBuf::from(out_dir).join(GENERATED_FILE);
+    if let Err(err) = std::fs::write(&out_path, generated) {
+        panic!("Could not write \"{}\": {}", out_path.display(), err);
+    }
+}
+
+/// The source of the `examples` test module
+fn example_tests(names: &[String], solution: &str) -> String {
+    let mut generated = String::from("#[cfg(test)]\nmod examples {\n");
+    for name in names {
+        writeln!(
+            generated,
+            "    #[test]\n    fn {}() {{\n        runner::check_fixture::<super::{}>({:?});\n    }}",
+            test_name(name),
+            solution,
+            name
+        )
+        .unwrap();
+    }
+    writeln!(
+        generated,
+        "    #[test]\n    fn generated_inputs_parse() {{\n        runner::check_generator::<super::{0}>();\n    }}\n    #[test]\n    fn variants_agree() {{\n        runner::check_variants::<super::{0}>();\n    }}",
+        solution
+    )
+    .unwrap();
+    generated.push_str("}\n");
+    generated
+}
+
+/// Turn a fixture file stem into a valid test function name
+fn test_name(fixture: &str) -> String {
+    let sanitized: String = fixture
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    if sanitized.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", sanitized)
+    } else {
+        sanitized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitises_test_names() {
+        assert_eq!(test_name("example"), "example");
+        assert_eq!(test_name("Big-Grid.v2"), "big_grid_v2");
+        assert_eq!(test_name("2x2"), "_2x2");
+        assert_eq!(test_name("é"), "_");
+    }
+
+    #[test]
+    fn generates_a_test_per_fixture() {
+        let names = ["1-line".to_string(), "example".to_string()];
+        let generated = example_tests(&names, "Day3");
+        assert!(generated.starts_with("#[cfg(test)]\nmod examples {\n"));
+        assert!(generated.contains(
+            "    #[test]\n    fn _1_line() {\n        runner::check_fixture::<super::Day3>(\"1-line\");\n    }\n"
+        ));
+        assert!(generated.contains("runner::check_fixture::<super::Day3>(\"example\");"));
+        assert!(generated.contains("runner::check_generator::<super::Day3>();"));
+        assert!(generated.contains("runner::check_variants::<super::Day3>();"));
+        assert!(generated.ends_with("}\n"));
+        assert!(generated.find("fn _1_line").unwrap() < generated.find("fn example").unwrap());
+    }
+}
@@ -0,0 +1,247 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+use toml::{Table, Value};
+
+const EXAMPLES_PATH: &str = "../examples";
+const INPUT_EXTENSION: &str = "txt";
+const ANSWERS_EXTENSION: &str = "toml";
+
+/// An example input along with the answers the solution is expected to
+/// produce for it. A fixture named `example` for day 3 lives in
+/// `examples/day3/example.txt` with its answers in `examples/day3/example.toml`.
+/// Puzzle parameters that differ from the real input go in a `[params]` table
+#[derive(Debug)]
+pub struct Fixture {
+    pub name: String,
+    pub path: PathBuf,
+    pub input: String,
+    pub expected: Expected,
+    pub params: BTreeMap<String, i64>,
+}
+
+/// Expected answers for a fixture. A part can be left out when the example
+/// only covers the other part
+#[derive(Debug, Default)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct FixtureFile {
+    #[serde(default, deserialize_with = "deserialize_answer")]
+    part1: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_answer")]
+    part2: Option<String>,
+    #[serde(default)]
+    params: BTreeMap<String, i64>,
+}
+
+/// Answers may be written as TOML integers or as strings, the latter being
+/// needed for anything that does not fit in an i64
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AnswerValue {
+    Integer(i64),
+    Text(String),
+}
+
+fn deserialize_answer<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = Option::<AnswerValue>::deserialize(deserializer)?;
+    Ok(value.map(|v| match v {
+        AnswerValue::Integer(i) => i.to_string(),
+        AnswerValue::Text(s) => s,
+    }))
+}
+
+pub fn examples_dir(day: usize) -> PathBuf {
+    PathBuf::from(EXAMPLES_PATH).join(format!("day{}", day))
+}
+
+/// Names of every fixture for the day, sorted. A day without an examples
+/// directory simply has no fixtures
+pub fn fixture_names(day: usize) -> Result<Vec<String>, String> {
+    fixture_names_in(&examples_dir(day))
+}
+
+fn fixture_names_in(dir: &Path) -> Result<Vec<String>, String> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return Ok(Vec::new()),
+    };
+
+    let mut names = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("Could not read \"{}\": {}", dir.display(), e))?
+            .path();
+
+        if path.extension().is_some_and(|e| e == INPUT_EXTENSION)
+            && let Some(stem) = path.file_stem()
+        {
+            names.push(stem.to_string_lossy().into_owned());
+        }
+    }
+    names.sort();
+
+    Ok(names)
+}
+
+pub fn load_fixture(day: usize, name: &str) -> Result<Fixture, String> {
+    load_fixture_in(&examples_dir(day), name)
+}
+
+fn load_fixture_in(dir: &Path, name: &str) -> Result<Fixture, String> {
+    let path = dir.join(name).with_extension(INPUT_EXTENSION);
+    let answers_path = path.with_extension(ANSWERS_EXTENSION);
+
+    let input = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Could not read fixture \"{}\": {}", path.display(), e))?;
+
+    let answers = std::fs::read_to_string(&answers_path).map_err(|e| {
+        format!(
+            "Could not read expected answers \"{}\": {}",
+            answers_path.display(),
+            e
+        )
+    })?;
+
+    let file: FixtureFile = toml::from_str(&answers).map_err(|e| {
+        format!(
+            "Could not parse expected answers \"{}\": {}",
+            answers_path.display(),
+            e
+        )
+    })?;
+
+    Ok(Fixture {
+        name: name.to_string(),
+        path,
+        input,
+        expected: Expected {
+            part1: file.part1,
+            part2: file.part2,
+        },
+        params: file.params,
+    })
+}
+
+/// Save a new fixture for the day, failing rather than overwriting one that
+/// already exists. The comment, if any, heads the answers file
+pub fn write_fixture(
+    day: usize,
+    name: &str,
+    input: &str,
+    expected: &Expected,
+    comment: Option<&str>,
+) -> Result<PathBuf, String> {
+    write_fixture_in(&examples_dir(day), name, input, expected, comment)
+}
+
+fn write_fixture_in(
+    dir: &Path,
+    name: &str,
+    input: &str,
+    expected: &Expected,
+    comment: Option<&str>,
+) -> Result<PathBuf, String> {
+    let path = dir.join(name).with_extension(INPUT_EXTENSION);
+    let answers_path = path.with_extension(ANSWERS_EXTENSION);
+    if path.exists() || answers_path.exists() {
+        return Err(format!("Fixture \"{}\" already exists", path.display()));
+    }
+
+    let mut answers = Table::new();
+    for (part, answer) in [("part1", &expected.part1), ("part2", &expected.part2)] {
+        if let Some(answer) = answer {
+            let value = match answer.parse() {
+                Ok(i) => Value::Integer(i),
+                Err(_) => Value::String(answer.clone()),
+            };
+            answers.insert(part.to_string(), value);
+        }
+    }
+
+    let mut contents = String::new();
+    for line in comment.into_iter().flat_map(str::lines) {
+        contents.push_str(&format!("# {}\n", line));
+    }
+    contents.push_str(&toml::to_string(&answers).map_err(|e| e.to_string())?);
+
+    std::fs::create_dir_all(dir)
+        .map_err(|e| format!("Could not create \"{}\": {}", dir.display(), e))?;
+    std::fs::write(&path, input)
+        .map_err(|e| format!("Could not write \"{}\": {}", path.display(), e))?;
+    std::fs::write(&answers_path, contents)
+        .map_err(|e| format!("Could not write \"{}\": {}", answers_path.display(), e))?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(test: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("fixtures-{}-{}", test, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn lists_inputs_in_order() {
+        let dir = temp_dir("names");
+        std::fs::create_dir_all(&dir).unwrap();
+        for file in ["b.txt", "a.txt", "a.toml", "notes.md", "10.txt"] {
+            std::fs::write(dir.join(file), "").unwrap();
+        }
+        assert_eq!(fixture_names_in(&dir).unwrap(), ["10", "a", "b"]);
+        assert_eq!(fixture_names_in(&dir.join("missing")), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn writes_fixtures_that_load_back() {
+        let dir = temp_dir("write");
+        let expected = Expected {
+            part1: Some("42".to_string()),
+            part2: Some("18446744073709551616".to_string()),
+        };
+        let path =
+            write_fixture_in(&dir, "big", "1\n2\n", &expected, Some("Found\nby hand")).unwrap();
+        assert_eq!(path, dir.join("big.txt"));
+        assert_eq!(
+            std::fs::read_to_string(dir.join("big.toml")).unwrap(),
+            "# Found\n# by hand\npart1 = 42\npart2 = \"18446744073709551616\"\n"
+        );
+
+        let fixture = load_fixture_in(&dir, "big").unwrap();
+        assert_eq!(fixture.input, "1\n2\n");
+        assert_eq!(fixture.expected.part1.as_deref(), Some("42"));
+        assert_eq!(fixture.expected.part2, expected.part2);
+
+        let again = write_fixture_in(&dir, "big", "", &Expected::default(), None);
+        assert!(again.unwrap_err().contains("already exists"));
+    }
+
+    #[test]
+    fn leaves_out_missing_answers() {
+        let dir = temp_dir("partial");
+        let expected = Expected {
+            part1: None,
+            part2: Some("7".to_string()),
+        };
+        write_fixture_in(&dir, "part2", "x\n", &expected, None).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(dir.join("part2.toml")).unwrap(),
+            "part2 = 7\n"
+        );
+        assert_eq!(load_fixture_in(&dir, "part2").unwrap().expected.part1, None);
+    }
+}
@@ -0,0 +1,5 @@
+mod codegen;
+mod fixture;
+
+pub use codegen::generate_example_tests;
+pub use fixture::{Expected, Fixture, examples_dir, fixture_names, load_fixture, write_fixture};
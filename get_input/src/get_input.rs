@@ -2,17 +2,30 @@ use std::{collections::HashMap, fs::OpenOptions, io::Write, path::PathBuf, str::
 
 const SECRETS_PATH: &str = "../get_input/secrets.toml";
 const SESSION_KEY: &str = "session";
-const CACHE_PATH: &str = "./cache/input.txt";
+const CACHE_DIR: &str = "./cache";
+pub const DEFAULT_PROFILE: &str = "default";
 
 pub fn get_input(day: usize) -> Result<String, String> {
-    if let Some(cached) = get_cached(CACHE_PATH) {
-        println!("Using cached local input \"{}\"", CACHE_PATH);
+    get_profile_input(day, DEFAULT_PROFILE)
+}
+
+/// Get the input for one of several puzzle profiles, for example a
+/// teammate's account. Each profile has its own cache file and its own
+/// session token in the secrets file, stored under `session_<profile>`
+pub fn get_profile_input(day: usize, profile: &str) -> Result<String, String> {
+    let cache_path = cache_path(profile)?;
+    if let Some(cached) = get_cached(&cache_path) {
+        eprintln!("Using cached local input \"{}\"", cache_path);
         return Ok(cached);
     }
 
-    println!("No input cache detected, fetching input");
+    eprintln!("No input cache detected, fetching input");
 
-    let session_token = format!("{}={}", SESSION_KEY, get_session_token(SECRETS_PATH)?);
+    let session_token = format!(
+        "{}={}",
+        SESSION_KEY,
+        get_session_token(SECRETS_PATH, &session_key(profile))?
+    );
 
     let uri = compose_uri(day);
 
@@ -42,16 +55,44 @@ pub fn get_input(day: usize) -> Result<String, String> {
 
     let content = content.map_err(|e| e.to_string())?;
 
-    store_cache(CACHE_PATH, &content)?;
+    store_cache(&cache_path, &content)?;
 
     Ok(content)
 }
 
+/// Profile names become part of the cache file name, so they are limited to
+/// characters that cannot lead outside the cache directory
+fn cache_path(profile: &str) -> Result<String, String> {
+    let valid = profile
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if profile.is_empty() || !valid {
+        return Err(format!(
+            "Invalid profile \"{}\", expected letters, digits, '_' and '-'",
+            profile
+        ));
+    }
+
+    if profile == DEFAULT_PROFILE {
+        Ok(format!("{}/input.txt", CACHE_DIR))
+    } else {
+        Ok(format!("{}/input-{}.txt", CACHE_DIR, profile))
+    }
+}
+
+fn session_key(profile: &str) -> String {
+    if profile == DEFAULT_PROFILE {
+        SESSION_KEY.to_string()
+    } else {
+        format!("{}_{}", SESSION_KEY, profile)
+    }
+}
+
 fn compose_uri(day: usize) -> String {
     format!("https://adventofcode.com/2025/day/{}/input", day)
 }
 
-fn get_session_token(secrets_path: &str) -> Result<String, String> {
+fn get_session_token(secrets_path: &str, key: &str) -> Result<String, String> {
     let secrets = std::fs::read_to_string(secrets_path)
         .map_err(|e| format!("Could not read secrets file \"{}\": {}", secrets_path, e))?;
 
@@ -59,10 +100,10 @@ fn get_session_token(secrets_path: &str) -> Result<String, String> {
         .map_err(|e| format!("Could not parse secrets file \"{}\": {}", secrets_path, e))?;
 
     secrets
-        .get(SESSION_KEY)
+        .get(key)
         .ok_or(format!(
             "Could not find key \"{}\" in secrets file \"{}\"",
-            SESSION_KEY, secrets_path
+            key, secrets_path
         ))
         .cloned()
 }
@@ -73,7 +114,9 @@ fn get_cached(path: &str) -> Option<String> {
 
 fn store_cache(path: &str, contents: &str) -> Result<(), String> {
     let os_path = PathBuf::from_str(path).map_err(|e| e.to_string())?;
-    let dir_path = os_path.parent().ok_or("Cache path has no parent".to_string())?;
+    let dir_path = os_path
+        .parent()
+        .ok_or("Cache path has no parent".to_string())?;
     std::fs::create_dir_all(dir_path).map_err(|e| e.to_string())?;
 
     let mut file = OpenOptions::new()
@@ -89,3 +132,27 @@ fn store_cache(path: &str, contents: &str) -> Result<(), String> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caches_each_profile_separately() {
+        assert_eq!(
+            cache_path(DEFAULT_PROFILE),
+            Ok("./cache/input.txt".to_string())
+        );
+        assert_eq!(
+            cache_path("alt_2-b"),
+            Ok("./cache/input-alt_2-b.txt".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_profiles_that_are_not_plain_names() {
+        for profile in ["", "../../x", "a/b", "a.b", "a b", "\\x"] {
+            assert!(cache_path(profile).is_err(), "{:?}", profile);
+        }
+    }
+}
@@ -1,3 +1,3 @@
 mod get_input;
 
-pub use get_input::get_input;
+pub use get_input::{DEFAULT_PROFILE, get_input, get_profile_input};
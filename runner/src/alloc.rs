@@ -0,0 +1,169 @@
+//! Allocation counts of each phase for `--alloc`. The counting allocator is
+//! only installed with the `count-allocations` feature, which the days turn
+//! on, so the `aoc` tool and the runner's own tests use the system allocator
+
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+/// Whether this build counts allocations, checked before anything runs
+pub const AVAILABLE: bool = cfg!(feature = "count-allocations");
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static GLOBAL: counting::CountingAllocator = counting::CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+// Live bytes are relative to the start of the measurement so freeing memory
+// allocated before it started can take this below zero
+static LIVE_BYTES: AtomicI64 = AtomicI64::new(0);
+static PEAK_BYTES: AtomicI64 = AtomicI64::new(0);
+
+#[cfg(any(test, feature = "count-allocations"))]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+
+    use super::*;
+
+    /// Forwards to the system allocator, counting allocations while a
+    /// measurement is in progress. When no measurement is running the only
+    /// overhead is a relaxed load per call
+    pub struct CountingAllocator;
+
+    fn record_allocation(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+        record_live_change(size as i64);
+    }
+
+    fn record_live_change(change: i64) {
+        let live = LIVE_BYTES.fetch_add(change, Ordering::Relaxed) + change;
+        PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+                record_allocation(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+                record_allocation(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            if ENABLED.load(Ordering::Relaxed) {
+                record_live_change(-(layout.size() as i64));
+            }
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+                // Count a reallocation as a fresh allocation of the new size
+                ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+                ALLOCATED_BYTES.fetch_add(new_size as u64, Ordering::Relaxed);
+                record_live_change(new_size as i64 - layout.size() as i64);
+            }
+            new_ptr
+        }
+    }
+}
+
+/// Heap usage of one phase of a solution
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// Highest number of bytes allocated by the phase and live at once
+    pub peak_bytes: u64,
+}
+
+/// Run `f` while counting every allocation made by the process. Only one
+/// measurement should run at a time
+pub fn measure_allocations<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    ALLOCATED_BYTES.store(0, Ordering::Relaxed);
+    LIVE_BYTES.store(0, Ordering::Relaxed);
+    PEAK_BYTES.store(0, Ordering::Relaxed);
+
+    ENABLED.store(true, Ordering::SeqCst);
+    let result = f();
+    ENABLED.store(false, Ordering::SeqCst);
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+        peak_bytes: PEAK_BYTES.load(Ordering::Relaxed).max(0) as u64,
+    };
+    (result, stats)
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{counting::CountingAllocator, *};
+    use std::{
+        alloc::{GlobalAlloc, Layout},
+        hint::black_box,
+    };
+
+    // Calls the allocator directly so, with the system allocator installed,
+    // nothing else the tests allocate is counted
+    #[test]
+    #[cfg_attr(
+        feature = "count-allocations",
+        ignore = "other tests allocate through the counting allocator"
+    )]
+    fn counts_allocations_and_peak() {
+        let allocator = CountingAllocator;
+        let first = Layout::array::<u8>(1000).unwrap();
+        let second = Layout::array::<u8>(600).unwrap();
+        let ((), stats) = measure_allocations(|| unsafe {
+            let ptr = black_box(allocator.alloc(first));
+            allocator.dealloc(ptr, first);
+            let ptr = black_box(allocator.alloc_zeroed(second));
+            let ptr = allocator.realloc(ptr, second, 800);
+            allocator.dealloc(ptr, Layout::array::<u8>(800).unwrap());
+        });
+
+        let expected = AllocStats {
+            allocations: 3,
+            bytes: 1000 + 600 + 800,
+            peak_bytes: 1000,
+        };
+        assert_eq!(stats, expected);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
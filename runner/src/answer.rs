@@ -0,0 +1,190 @@
+use std::fmt::{Display, Write};
+
+use serde::{Deserialize, Serialize};
+
+/// The answer to a part. Whatever the type, an answer is submitted and
+/// compared through its canonical rendering
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "AnswerRepr", try_from = "AnswerRepr")]
+pub enum Answer {
+    Integer(i64),
+    /// Integers that do not fit in 64 bits
+    BigInteger(i128),
+    Text(String),
+}
+
+impl Answer {
+    /// The form the puzzle expects to be given: plain decimal digits for
+    /// integers, text without trailing whitespace on any line
+    pub fn canonical(&self) -> String {
+        match self {
+            Answer::Integer(i) => i.to_string(),
+            Answer::BigInteger(i) => i.to_string(),
+            Answer::Text(t) => canonical_text(t),
+        }
+    }
+
+    /// Whether this answer matches one written down elsewhere, such as an
+    /// example fixture's expected answer
+    pub fn matches(&self, expected: &str) -> bool {
+        self.canonical() == canonical_text(expected)
+    }
+
+    /// Render a line-by-line diff against an expected answer. Answers are
+    /// usually a single line but some puzzles produce multi-line text
+    pub fn diff(&self, expected: &str) -> String {
+        let expected = canonical_text(expected);
+        let actual = self.canonical();
+        let expected_lines = expected.lines().collect::<Vec<_>>();
+        let actual_lines = actual.lines().collect::<Vec<_>>();
+
+        let mut diff = String::new();
+        for i in 0..expected_lines.len().max(actual_lines.len()) {
+            match (expected_lines.get(i), actual_lines.get(i)) {
+                (Some(e), Some(a)) if e == a => writeln!(diff, "    {}", e).unwrap(),
+                (e, a) => {
+                    if let Some(e) = e {
+                        writeln!(diff, "  - {}", e).unwrap();
+                    }
+                    if let Some(a) = a {
+                        writeln!(diff, "  + {}", a).unwrap();
+                    }
+                }
+            }
+        }
+        diff
+    }
+
+    fn kind(&self) -> AnswerKind {
+        match self {
+            Answer::Integer(_) => AnswerKind::Integer,
+            Answer::BigInteger(_) => AnswerKind::BigInteger,
+            Answer::Text(_) => AnswerKind::Text,
+        }
+    }
+}
+
+fn canonical_text(t: &str) -> String {
+    t.lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.canonical())
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    match i64::try_from(value) {
+                        Ok(i) => Answer::Integer(i),
+                        Err(_) => Answer::BigInteger(value as i128),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, i128, u8, u16, u32, u64, usize, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Answers are serialized with their value as a string so that large
+/// integers survive JSON consumers that only have doubles
+#[derive(Serialize, Deserialize)]
+struct AnswerRepr {
+    kind: AnswerKind,
+    value: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum AnswerKind {
+    Integer,
+    BigInteger,
+    Text,
+}
+
+impl From<Answer> for AnswerRepr {
+    fn from(answer: Answer) -> Self {
+        Self {
+            kind: answer.kind(),
+            value: answer.canonical(),
+        }
+    }
+}
+
+impl TryFrom<AnswerRepr> for Answer {
+    type Error = String;
+
+    fn try_from(repr: AnswerRepr) -> Result<Self, Self::Error> {
+        let invalid = |e| format!("Invalid integer answer \"{}\": {}", repr.value, e);
+        match repr.kind {
+            AnswerKind::Integer => repr.value.parse().map(Answer::Integer).map_err(invalid),
+            AnswerKind::BigInteger => repr.value.parse().map(Answer::BigInteger).map_err(invalid),
+            AnswerKind::Text => Ok(Answer::Text(repr.value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_use_the_smallest_variant() {
+        assert_eq!(Answer::from(42_u64), Answer::Integer(42));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(u64::MAX as i128));
+        assert_eq!(Answer::from(-3_i16), Answer::Integer(-3));
+    }
+
+    #[test]
+    fn canonical_text_drops_trailing_whitespace() {
+        let answer = Answer::from("#.# \n.#.\n\n");
+        assert_eq!(answer.canonical(), "#.#\n.#.");
+        assert!(answer.matches("#.#\n.#.  \n"));
+    }
+
+    #[test]
+    fn diff_marks_changed_lines() {
+        assert_eq!(Answer::from(13).diff("12"), "  - 12\n  + 13\n");
+    }
+
+    #[test]
+    fn diff_keeps_matching_lines() {
+        assert_eq!(
+            Answer::from("#..\n..#\n###").diff("#..\n.#."),
+            "    #..\n  - .#.\n  + ..#\n  + ###\n"
+        );
+    }
+
+    #[test]
+    fn serializes_values_as_strings() {
+        let answer = Answer::from(u64::MAX);
+        let json = serde_json::to_string(&answer).unwrap();
+        assert_eq!(
+            json,
+            r#"{"kind":"big_integer","value":"18446744073709551615"}"#
+        );
+        assert_eq!(serde_json::from_str::<Answer>(&json).unwrap(), answer);
+    }
+}
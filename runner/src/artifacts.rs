@@ -0,0 +1,151 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use serde::{Serialize, de::DeserializeOwned};
+
+/// Artifacts live next to the cached puzzle input, relative to the day's crate
+const ARTIFACTS_PATH: &str = "./cache/artifacts";
+
+/// Expensive intermediate structures saved between runs. An artifact is keyed
+/// by the day, a hash of the input, its name and a version the solution bumps
+/// whenever the structure or the code building it changes, so a different
+/// input or profile never picks up a stale artifact
+#[derive(Debug, Clone)]
+pub struct ArtifactStore {
+    dir: PathBuf,
+    day: usize,
+    input_hash: u64,
+}
+
+impl ArtifactStore {
+    pub fn new(day: usize, input: &str) -> Self {
+        Self::in_dir(ARTIFACTS_PATH, day, input)
+    }
+
+    pub fn in_dir(dir: impl Into<PathBuf>, day: usize, input: &str) -> Self {
+        Self {
+            dir: dir.into(),
+            day,
+            input_hash: fnv1a(input.as_bytes()),
+        }
+    }
+
+    fn path(&self, name: &str, version: u32) -> PathBuf {
+        self.dir.join(format!(
+            "day{}-{}-v{}-{:016x}.cbor",
+            self.day, name, version, self.input_hash
+        ))
+    }
+
+    /// Load the artifact if it was saved before, otherwise build and save it.
+    /// An artifact that cannot be read is rebuilt rather than failing the part
+    pub fn get_or_build<T: Serialize + DeserializeOwned>(
+        &self,
+        name: &str,
+        version: u32,
+        build: impl FnOnce() -> Result<T, String>,
+    ) -> Result<T, String> {
+        let path = self.path(name, version);
+        match load(&path) {
+            Ok(Some(artifact)) => return Ok(artifact),
+            Ok(None) => {}
+            Err(err) => eprintln!("Rebuilding artifact: {}", err),
+        }
+
+        let artifact = build()?;
+        if let Err(err) = save(&path, &artifact) {
+            eprintln!("Could not save artifact: {}", err);
+        }
+        Ok(artifact)
+    }
+}
+
+fn load<T: DeserializeOwned>(path: &Path) -> Result<Option<T>, String> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(_) => return Ok(None),
+    };
+    ciborium::from_reader(BufReader::new(file))
+        .map(Some)
+        .map_err(|e| format!("Could not read \"{}\": {}", path.display(), e))
+}
+
+/// Write to a temporary file first and rename it into place, so an
+/// interrupted write never leaves a partial artifact behind
+fn save<T: Serialize>(path: &Path, artifact: &T) -> Result<(), String> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    std::fs::create_dir_all(dir)
+        .map_err(|e| format!("Could not create \"{}\": {}", dir.display(), e))?;
+
+    let temp = path.with_extension(format!("tmp{}", std::process::id()));
+    let written = File::create(&temp)
+        .map_err(|e| e.to_string())
+        .and_then(|file| {
+            let mut writer = BufWriter::new(file);
+            ciborium::into_writer(artifact, &mut writer).map_err(|e| e.to_string())?;
+            writer.flush().map_err(|e| e.to_string())
+        })
+        .and_then(|_| std::fs::rename(&temp, path).map_err(|e| e.to_string()));
+
+    written.map_err(|e| {
+        let _ = std::fs::remove_file(&temp);
+        format!("Could not write \"{}\": {}", path.display(), e)
+    })
+}
+
+/// FNV-1a, used over the standard hasher because its output must stay the
+/// same across Rust versions for saved artifacts to be found again
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ *b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_store(test: &str, input: &str) -> ArtifactStore {
+        let dir = std::env::temp_dir().join(format!("artifacts-{}-{}", test, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        ArtifactStore::in_dir(dir, 9, input)
+    }
+
+    #[test]
+    fn hash_is_stable() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+    }
+
+    #[test]
+    fn builds_once_per_input() {
+        let store = temp_store("once", "1,2\n");
+        let built = store.get_or_build("pairs", 1, || Ok(vec![1, 2])).unwrap();
+        let loaded: Vec<i32> = store
+            .get_or_build("pairs", 1, || Err("Built twice".to_string()))
+            .unwrap();
+        assert_eq!(built, loaded);
+
+        let other_input = ArtifactStore::in_dir(&store.dir, 9, "3,4\n");
+        let rebuilt = other_input.get_or_build("pairs", 1, || Ok(vec![3, 4]));
+        assert_eq!(rebuilt.unwrap(), vec![3, 4]);
+    }
+
+    #[test]
+    fn rebuilds_corrupt_artifacts() {
+        let store = temp_store("corrupt", "1,2\n");
+        let path = store.path("pairs", 1);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, b"\xff\xff").unwrap();
+
+        let rebuilt = store.get_or_build("pairs", 1, || Ok(vec![1, 2]));
+        assert_eq!(rebuilt.unwrap(), vec![1, 2]);
+        let loaded: Vec<i32> = store
+            .get_or_build("pairs", 1, || Err("Built twice".to_string()))
+            .unwrap();
+        assert_eq!(loaded, vec![1, 2]);
+    }
+}
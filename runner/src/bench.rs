@@ -0,0 +1,343 @@
+use std::{
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    alloc::{AllocStats, format_bytes, measure_allocations},
+    context::Context,
+    reports::{read_report, write_report},
+    solution::Solution,
+};
+
+const RESULTS_JSON: &str = "bench.json";
+const RESULTS_CSV: &str = "bench.csv";
+const BASELINE_JSON: &str = "baseline.json";
+
+#[derive(Debug, Clone)]
+pub struct BenchOptions {
+    pub runs: usize,
+    pub warmup: usize,
+    /// Median slowdown over the baseline, in percent, that counts as a regression
+    pub threshold: f64,
+    pub save_baseline: bool,
+    /// Count allocations in one extra, untimed, run of each phase
+    pub count_allocations: bool,
+    /// Names inputs other than the puzzle input, such as generated ones.
+    /// Their results and baseline are kept apart from the puzzle input's
+    pub input_label: Option<String>,
+}
+
+/// Timing statistics for one phase of a solution
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhaseStats {
+    pub phase: String,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<AllocStats>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub day: usize,
+    pub phases: Vec<PhaseStats>,
+}
+
+impl BenchReport {
+    pub fn phase(&self, phase: &str) -> Option<&PhaseStats> {
+        self.phases.iter().find(|p| p.phase == phase)
+    }
+}
+
+/// Benchmark parsing, both parts and every variant, write the results to the
+/// day's report directory and compare them with the saved baseline. Returns
+/// whether any phase regressed
+pub fn bench<S: Solution>(
+    input: &str,
+    ctx: &Context,
+    options: &BenchOptions,
+) -> Result<bool, String> {
+    let parsed = S::parse(input).map_err(|e| format!("Could not parse input: {}", e))?;
+
+    let mut phases = vec![measure("parse", options, || {
+        black_box(S::parse(black_box(input)).ok());
+    })];
+
+    // A part that cannot complete has nothing worth timing
+    if let Err(err) = S::part1(&parsed, ctx) {
+        println!("Skipping part 1: {}", err);
+    } else {
+        phases.push(measure("part1", options, || {
+            black_box(S::part1(black_box(&parsed), ctx).ok());
+        }));
+    }
+
+    if let Err(err) = S::part2(&parsed, ctx) {
+        println!("Skipping part 2: {}", err);
+    } else {
+        phases.push(measure("part2", options, || {
+            black_box(S::part2(black_box(&parsed), ctx).ok());
+        }));
+    }
+
+    // Variants are timed alongside the parts they could replace
+    for variant in S::VARIANTS {
+        let phase = format!("part{}:{}", variant.part, variant.name);
+        if let Err(err) = (variant.solve)(&parsed, ctx) {
+            println!("Skipping {}: {}", phase, err);
+        } else {
+            phases.push(measure(&phase, options, || {
+                black_box((variant.solve)(black_box(&parsed), ctx).ok());
+            }));
+        }
+    }
+
+    let report = BenchReport {
+        day: S::DAY,
+        phases,
+    };
+
+    let file_name = |name: &str| match &options.input_label {
+        Some(label) => name.replacen('.', &format!("-{}.", label), 1),
+        None => name.to_string(),
+    };
+
+    let baseline = read_report(S::DAY, &file_name(BASELINE_JSON))
+        .map(|b| serde_json::from_str::<BenchReport>(&b))
+        .transpose()
+        .map_err(|e| format!("Could not parse the saved baseline: {}", e))?;
+
+    let comparisons = compare(&report, baseline.as_ref(), options.threshold);
+    print!("{}", render_table(&comparisons));
+
+    let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+    let json_path = write_report(S::DAY, &file_name(RESULTS_JSON), &json)?;
+    let csv_path = write_report(S::DAY, &file_name(RESULTS_CSV), to_csv(&report))?;
+    println!(
+        "Results written to \"{}\" and \"{}\"",
+        json_path.display(),
+        csv_path.display()
+    );
+
+    if options.save_baseline {
+        let path = write_report(S::DAY, &file_name(BASELINE_JSON), &json)?;
+        println!("Saved baseline \"{}\"", path.display());
+    }
+
+    Ok(comparisons.iter().any(|c| c.regressed))
+}
+
+fn measure(phase: &str, options: &BenchOptions, mut f: impl FnMut()) -> PhaseStats {
+    for _ in 0..options.warmup {
+        f();
+    }
+
+    let samples = (0..options.runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+
+    let mut stats = summarise(phase, samples);
+    if options.count_allocations {
+        stats.allocations = Some(measure_allocations(f).1);
+    }
+    stats
+}
+
+fn summarise(phase: &str, mut samples: Vec<Duration>) -> PhaseStats {
+    samples.sort();
+    PhaseStats {
+        phase: phase.to_string(),
+        runs: samples.len(),
+        min_ns: samples[0].as_nanos() as u64,
+        median_ns: percentile(&samples, 50.0).as_nanos() as u64,
+        p95_ns: percentile(&samples, 95.0).as_nanos() as u64,
+        allocations: None,
+    }
+}
+
+/// Nearest-rank percentile of sorted samples
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[derive(Debug)]
+struct Comparison<'a> {
+    current: &'a PhaseStats,
+    baseline_median_ns: Option<u64>,
+    regressed: bool,
+}
+
+impl Comparison<'_> {
+    fn change_percent(&self) -> Option<f64> {
+        self.baseline_median_ns
+            .map(|b| (self.current.median_ns as f64 - b as f64) / b.max(1) as f64 * 100.0)
+    }
+}
+
+fn compare<'a>(
+    report: &'a BenchReport,
+    baseline: Option<&BenchReport>,
+    threshold: f64,
+) -> Vec<Comparison<'a>> {
+    report
+        .phases
+        .iter()
+        .map(|current| {
+            let baseline_median_ns = baseline
+                .and_then(|b| b.phase(&current.phase))
+                .map(|b| b.median_ns);
+            let mut comparison = Comparison {
+                current,
+                baseline_median_ns,
+                regressed: false,
+            };
+            comparison.regressed = comparison.change_percent().is_some_and(|c| c > threshold);
+            comparison
+        })
+        .collect()
+}
+
+fn render_table(comparisons: &[Comparison]) -> String {
+    let show_allocations = comparisons.iter().any(|c| c.current.allocations.is_some());
+    let width = comparisons
+        .iter()
+        .map(|c| c.current.phase.len() + 2)
+        .fold(8, usize::max);
+
+    let mut table = format!(
+        "{:<width$}{:>12}{:>12}{:>12}{:>12}{:>10}",
+        "phase",
+        "min",
+        "median",
+        "p95",
+        "baseline",
+        "change",
+        width = width
+    );
+    if show_allocations {
+        write!(
+            table,
+            "{:>14}{:>12}{:>12}",
+            "allocations", "allocated", "peak"
+        )
+        .unwrap();
+    }
+    table.push('\n');
+
+    for c in comparisons {
+        let (baseline, change) = match (c.baseline_median_ns, c.change_percent()) {
+            (Some(b), Some(change)) => (format_ns(b), format!("{:+.1}%", change)),
+            _ => ("-".to_string(), "-".to_string()),
+        };
+        write!(
+            table,
+            "{:<width$}{:>12}{:>12}{:>12}{:>12}{:>10}",
+            c.current.phase,
+            format_ns(c.current.min_ns),
+            format_ns(c.current.median_ns),
+            format_ns(c.current.p95_ns),
+            baseline,
+            change,
+            width = width
+        )
+        .unwrap();
+        if show_allocations {
+            let a = c.current.allocations.unwrap_or_default();
+            write!(
+                table,
+                "{:>14}{:>12}{:>12}",
+                a.allocations,
+                format_bytes(a.bytes),
+                format_bytes(a.peak_bytes)
+            )
+            .unwrap();
+        }
+        if c.regressed {
+            table.push_str("  REGRESSION");
+        }
+        table.push('\n');
+    }
+    table
+}
+
+fn to_csv(report: &BenchReport) -> String {
+    let mut csv =
+        "day,phase,runs,min_ns,median_ns,p95_ns,allocations,bytes,peak_bytes\n".to_string();
+    for p in &report.phases {
+        let allocations = match p.allocations {
+            Some(a) => format!("{},{},{}", a.allocations, a.bytes, a.peak_bytes),
+            None => ",,".to_string(),
+        };
+        writeln!(
+            csv,
+            "{},{},{},{},{},{},{}",
+            report.day, p.phase, p.runs, p.min_ns, p.median_ns, p.p95_ns, allocations
+        )
+        .unwrap();
+    }
+    csv
+}
+
+pub fn format_ns(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(phase: &str, median_ns: u64) -> PhaseStats {
+        PhaseStats {
+            phase: phase.to_string(),
+            runs: 1,
+            min_ns: median_ns,
+            median_ns,
+            p95_ns: median_ns,
+            allocations: None,
+        }
+    }
+
+    #[test]
+    fn summarises_samples() {
+        let samples = (1..=20).rev().map(Duration::from_nanos).collect();
+        let stats = summarise("part1", samples);
+        assert_eq!(stats.runs, 20);
+        assert_eq!(stats.min_ns, 1);
+        assert_eq!(stats.median_ns, 10);
+        assert_eq!(stats.p95_ns, 19);
+    }
+
+    #[test]
+    fn single_sample_is_every_percentile() {
+        let stats = summarise("parse", vec![Duration::from_nanos(7)]);
+        assert_eq!((stats.min_ns, stats.median_ns, stats.p95_ns), (7, 7, 7));
+    }
+
+    #[test]
+    fn flags_regressions_above_threshold() {
+        let report = BenchReport {
+            day: 1,
+            phases: vec![stats("parse", 105), stats("part1", 200), stats("part2", 50)],
+        };
+        let baseline = BenchReport {
+            day: 1,
+            phases: vec![stats("parse", 100), stats("part1", 100)],
+        };
+
+        let comparisons = compare(&report, Some(&baseline), 10.0);
+        let regressed = comparisons.iter().map(|c| c.regressed).collect::<Vec<_>>();
+        assert_eq!(regressed, [false, true, false]);
+        assert_eq!(comparisons[2].baseline_median_ns, None);
+    }
+}
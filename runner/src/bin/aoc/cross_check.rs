@@ -0,0 +1,321 @@
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    path::{Path, PathBuf},
+    process::Command,
+    time::Duration,
+};
+
+use get_input::DEFAULT_PROFILE;
+use runner::{Answer, PartReport, RunReport};
+use serde::Deserialize;
+
+use crate::days::{
+    Day, Run, build_day, discover_days, failure_message, last_line, parse_run_report, run_day,
+    run_with_timeout,
+};
+
+#[derive(Debug)]
+pub struct CrossCheckOptions {
+    /// File listing the reference solvers, their commands run from its directory
+    pub config: PathBuf,
+    /// Only check these days, every day when empty
+    pub days: Vec<usize>,
+    /// Only check these profiles, every cached one when empty
+    pub profiles: Vec<String>,
+    /// Only run these references, every configured one when empty
+    pub references: Vec<String>,
+    pub timeout: Duration,
+}
+
+/// An external solver, such as a teammate's solutions in another language.
+/// Its command is run through `sh -c` with the input on stdin and the day,
+/// part and input path in `AOC_DAY`, `AOC_PART` and `AOC_INPUT`. Whatever it
+/// prints is its answer
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Reference {
+    command: String,
+    /// Days the reference solves, every day when empty
+    #[serde(default)]
+    days: Vec<usize>,
+    /// Parts the reference solves, both when empty
+    #[serde(default)]
+    parts: Vec<usize>,
+}
+
+impl Reference {
+    fn parts(&self, day: usize) -> Vec<usize> {
+        if !self.days.is_empty() && !self.days.contains(&day) {
+            Vec::new()
+        } else if self.parts.is_empty() {
+            vec![1, 2]
+        } else {
+            self.parts.clone()
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Tally {
+    agreed: usize,
+    mismatched: usize,
+    failed: usize,
+}
+
+/// Run every configured reference over every cached input of each day and
+/// compare its answers with ours. Returns whether every answer agreed
+pub fn cross_check(options: &CrossCheckOptions) -> Result<bool, String> {
+    let mut references = load_config(&options.config)?;
+    if !options.references.is_empty() {
+        if let Some(unknown) = options
+            .references
+            .iter()
+            .find(|r| !references.contains_key(*r))
+        {
+            return Err(format!(
+                "There is no reference \"{}\" in \"{}\"",
+                unknown,
+                options.config.display()
+            ));
+        }
+        references.retain(|name, _| options.references.contains(name));
+    }
+    let config_dir = match options.config.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+
+    let mut tally = Tally::default();
+    for day in discover_days()? {
+        if !options.days.is_empty() && !options.days.contains(&day.number) {
+            continue;
+        }
+        if references.values().all(|r| r.parts(day.number).is_empty()) {
+            continue;
+        }
+        let inputs = cached_inputs(&day)
+            .into_iter()
+            .filter(|(p, _)| options.profiles.is_empty() || options.profiles.contains(p))
+            .collect::<Vec<_>>();
+        if inputs.is_empty() {
+            continue;
+        }
+
+        let executable = match build_day(&day) {
+            Ok(executable) => executable,
+            Err(err) => {
+                println!("day{}: {}", day.number, err);
+                tally.failed += 1;
+                continue;
+            }
+        };
+
+        for (profile, input) in inputs {
+            let args = ["--profile", &profile, "--json", "--quiet"].map(str::to_string);
+            let ours = run_day(&executable, &day, &args, options.timeout)
+                .and_then(|run| our_report(&run, options.timeout));
+
+            for (name, reference) in &references {
+                for part in reference.parts(day.number) {
+                    let label = format!("day{} {} part {} {}", day.number, profile, part, name);
+                    let theirs =
+                        run_reference(reference, config_dir, &day, part, &input, options.timeout);
+                    let ours = ours.as_ref().map(|report| match part {
+                        1 => &report.part1,
+                        _ => &report.part2,
+                    });
+                    match compare(ours, theirs) {
+                        Ok(answer) => {
+                            tally.agreed += 1;
+                            println!("{}: ok ({})", label, answer);
+                        }
+                        Err(Outcome::Mismatch(diff)) => {
+                            tally.mismatched += 1;
+                            print!("{}: MISMATCH\n{}", label, diff);
+                        }
+                        Err(Outcome::Failed(reason)) => {
+                            tally.failed += 1;
+                            println!("{}: {}", label, reason);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    println!(
+        "{} agreed, {} mismatched, {} could not be compared",
+        tally.agreed, tally.mismatched, tally.failed
+    );
+    Ok(tally.mismatched == 0 && tally.failed == 0)
+}
+
+fn load_config(path: &Path) -> Result<BTreeMap<String, Reference>, String> {
+    let config = std::fs::read_to_string(path)
+        .map_err(|e| format!("Could not read \"{}\": {}", path.display(), e))?;
+    let references: BTreeMap<String, Reference> = toml::from_str(&config)
+        .map_err(|e| format!("Could not parse \"{}\": {}", path.display(), e))?;
+    if references.is_empty() {
+        return Err(format!("No references in \"{}\"", path.display()));
+    }
+    Ok(references)
+}
+
+/// The day's cached puzzle inputs along with the profile each belongs to,
+/// named as the input cache names them
+fn cached_inputs(day: &Day) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = std::fs::read_dir(day.dir.join("cache")) else {
+        return Vec::new();
+    };
+
+    let mut inputs = entries
+        .flatten()
+        .filter_map(|entry| {
+            let profile = profile_of(&entry.file_name().to_string_lossy())?;
+            Some((profile, entry.path()))
+        })
+        .collect::<Vec<_>>();
+    inputs.sort();
+    inputs
+}
+
+fn profile_of(file_name: &str) -> Option<String> {
+    match file_name.strip_suffix(".txt")? {
+        "input" => Some(DEFAULT_PROFILE.to_string()),
+        name => name.strip_prefix("input-").map(str::to_string),
+    }
+}
+
+fn our_report(run: &Run, timeout: Duration) -> Result<RunReport, String> {
+    match run.success {
+        None => Err(format!("ours timed out after {:?}", timeout)),
+        Some(false) => Err(format!("ours failed: {}", failure_message(&run.stderr))),
+        Some(true) => parse_run_report(&run.stdout).ok_or("ours printed no report".to_string()),
+    }
+}
+
+fn run_reference(
+    reference: &Reference,
+    dir: &Path,
+    day: &Day,
+    part: usize,
+    input: &Path,
+    timeout: Duration,
+) -> Result<String, String> {
+    let stdin =
+        File::open(input).map_err(|e| format!("Could not read \"{}\": {}", input.display(), e))?;
+    let input = std::path::absolute(input).map_err(|e| e.to_string())?;
+
+    let mut command = Command::new("sh");
+    command
+        .args(["-c", &reference.command])
+        .current_dir(dir)
+        .env("AOC_DAY", day.number.to_string())
+        .env("AOC_PART", part.to_string())
+        .env("AOC_INPUT", input)
+        .stdin(stdin);
+    let run = run_with_timeout(&mut command, timeout)
+        .map_err(|e| format!("reference could not start: {}", e))?;
+
+    match run.success {
+        None => Err(format!("reference timed out after {:?}", timeout)),
+        Some(false) => Err(format!("reference failed: {}", last_line(&run.stderr))),
+        Some(true) if run.stdout.trim().is_empty() => {
+            Err("reference printed no answer".to_string())
+        }
+        Some(true) => Ok(run.stdout),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    /// A diff of the reference's answer against ours
+    Mismatch(String),
+    Failed(String),
+}
+
+fn compare(
+    ours: Result<&PartReport, &String>,
+    theirs: Result<String, String>,
+) -> Result<Answer, Outcome> {
+    let ours = ours.map_err(|e| Outcome::Failed(e.clone()))?;
+    let theirs = theirs.map_err(Outcome::Failed)?;
+    let answer = match (&ours.answer, &ours.error) {
+        (Some(answer), _) => answer,
+        (None, error) => {
+            let error = error.as_deref().unwrap_or("no answer");
+            return Err(Outcome::Failed(format!(
+                "ours could not complete: {}",
+                error
+            )));
+        }
+    };
+
+    if answer.matches(&theirs) {
+        Ok(answer.clone())
+    } else {
+        Err(Outcome::Mismatch(answer.diff(&theirs)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::PhaseReport;
+
+    fn part(answer: Option<Answer>, error: Option<&str>) -> PartReport {
+        PartReport {
+            phase: PhaseReport::default(),
+            answer,
+            error: error.map(str::to_string),
+            timed_out: false,
+        }
+    }
+
+    #[test]
+    fn parses_references() {
+        let config = r#"
+            [alice]
+            command = "python3 solve.py"
+
+            [bob]
+            command = "./bob $AOC_DAY"
+            days = [1, 3]
+            parts = [2]
+        "#;
+        let references: BTreeMap<String, Reference> = toml::from_str(config).unwrap();
+        assert_eq!(references["alice"].parts(5), [1, 2]);
+        assert_eq!(references["bob"].parts(3), [2]);
+        assert!(references["bob"].parts(2).is_empty());
+    }
+
+    #[test]
+    fn names_profiles_like_the_cache() {
+        assert_eq!(profile_of("input.txt").as_deref(), Some(DEFAULT_PROFILE));
+        assert_eq!(profile_of("input-alice.txt").as_deref(), Some("alice"));
+        assert_eq!(profile_of("artifacts"), None);
+        assert_eq!(profile_of("notes.txt"), None);
+    }
+
+    #[test]
+    fn compares_answers() {
+        let ours = part(Some(Answer::Integer(357)), None);
+        assert_eq!(
+            compare(Ok(&ours), Ok("357\n".to_string())),
+            Ok(Answer::Integer(357))
+        );
+        assert_eq!(
+            compare(Ok(&ours), Ok("358\n".to_string())),
+            Err(Outcome::Mismatch("  - 358\n  + 357\n".to_string()))
+        );
+
+        let unsolved = part(None, Some("Not solved yet"));
+        assert_eq!(
+            compare(Ok(&unsolved), Ok("1".to_string())),
+            Err(Outcome::Failed(
+                "ours could not complete: Not solved yet".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+    process::{Child, Command, Stdio},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use runner::{EXIT_TIMED_OUT, RunReport};
+use wait_timeout::ChildExt;
+
+const DAYS_PATH: &str = "..";
+
+/// A day's crate found next to the runner
+#[derive(Debug, Clone)]
+pub struct Day {
+    pub number: usize,
+    pub dir: PathBuf,
+}
+
+/// Every `dayN` crate in the repository, in day order
+pub fn discover_days() -> Result<Vec<Day>, String> {
+    let entries = std::fs::read_dir(DAYS_PATH)
+        .map_err(|e| format!("Could not read \"{}\": {}", DAYS_PATH, e))?;
+
+    let mut days = Vec::new();
+    for entry in entries {
+        let dir = entry.map_err(|e| e.to_string())?.path();
+        let number = dir
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.strip_prefix("day"))
+            .and_then(|n| n.parse().ok());
+
+        if let Some(number) = number
+            && dir.join("Cargo.toml").is_file()
+        {
+            days.push(Day { number, dir });
+        }
+    }
+    days.sort_by_key(|d| d.number);
+
+    Ok(days)
+}
+
+pub fn find_day(number: usize) -> Result<Day, String> {
+    discover_days()?
+        .into_iter()
+        .find(|d| d.number == number)
+        .ok_or(format!("There is no day{} crate", number))
+}
+
+fn cargo() -> Command {
+    Command::new(std::env::var("CARGO").unwrap_or("cargo".to_string()))
+}
+
+/// Build the day in release mode and return the path of its executable
+pub fn build_day(day: &Day) -> Result<PathBuf, String> {
+    let output = cargo()
+        .args(["build", "--release", "--quiet", "--message-format=json"])
+        .current_dir(&day.dir)
+        .output()
+        .map_err(|e| format!("Could not run cargo: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Build failed: {}", last_line(&stderr)));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout
+        .lines()
+        .filter_map(|l| serde_json::from_str::<serde_json::Value>(l).ok())
+        .filter(|m| m["reason"] == "compiler-artifact")
+        .find_map(|m| m["executable"].as_str().map(PathBuf::from))
+        .ok_or("Build produced no executable".to_string())
+}
+
+#[derive(Debug)]
+pub struct Run {
+    pub stdout: String,
+    pub stderr: String,
+    pub elapsed: Duration,
+    /// None when the run was killed for exceeding its timeout, or stopped
+    /// itself after a part ran past its budget
+    pub success: Option<bool>,
+}
+
+/// Run a day's executable from its crate directory, killing it if it has not
+/// finished within the timeout
+pub fn run_day(
+    executable: &Path,
+    day: &Day,
+    args: &[String],
+    timeout: Duration,
+) -> Result<Run, String> {
+    let mut command = Command::new(executable);
+    command
+        .args(args)
+        .current_dir(&day.dir)
+        .stdin(Stdio::null());
+    run_with_timeout(&mut command, timeout)
+        .map_err(|e| format!("Could not start \"{}\": {}", executable.display(), e))
+}
+
+/// Run a command capturing its output, killing it if it has not finished
+/// within the timeout
+pub fn run_with_timeout(command: &mut Command, timeout: Duration) -> Result<Run, String> {
+    let start = Instant::now();
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+
+    // Drain the pipes while waiting so a chatty day cannot block on a full pipe
+    let stdout = drain(&mut child, |c| c.stdout.take().map(|p| Box::new(p) as _));
+    let stderr = drain(&mut child, |c| c.stderr.take().map(|p| Box::new(p) as _));
+
+    let status = child.wait_timeout(timeout).map_err(|e| e.to_string())?;
+    let success = match status {
+        Some(status) if status.code() == Some(EXIT_TIMED_OUT) => None,
+        Some(status) => Some(status.success()),
+        None => {
+            child.kill().map_err(|e| e.to_string())?;
+            child.wait().map_err(|e| e.to_string())?;
+            None
+        }
+    };
+    let elapsed = start.elapsed();
+
+    Ok(Run {
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+        elapsed,
+        success,
+    })
+}
+
+type Pipe = Box<dyn Read + Send>;
+
+fn drain(child: &mut Child, take: impl FnOnce(&mut Child) -> Option<Pipe>) -> JoinHandle<String> {
+    let pipe = take(child);
+    thread::spawn(move || {
+        let mut contents = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut contents);
+        }
+        String::from_utf8_lossy(&contents).into_owned()
+    })
+}
+
+pub fn last_line(s: &str) -> &str {
+    s.lines().rev().find(|l| !l.trim().is_empty()).unwrap_or("")
+}
+
+/// A panic reported in a day's stderr
+#[derive(Debug, PartialEq, Eq)]
+pub struct Panic {
+    /// File, line and column of the panic, such as `src/lib.rs:3:5`
+    pub location: String,
+    /// First line of the panic message
+    pub message: String,
+}
+
+/// Every panic in a day's stderr, in the order they happened
+pub fn panics(stderr: &str) -> Vec<Panic> {
+    let mut panics = Vec::new();
+    let mut lines = stderr.lines();
+    while let Some(line) = lines.next() {
+        let Some((_, location)) = line.split_once("panicked at ") else {
+            continue;
+        };
+        let location = location.trim_end_matches(':').to_string();
+        let message = lines.next().unwrap_or(line).to_string();
+        panics.push(Panic { location, message });
+    }
+    panics
+}
+
+/// Why a day exited with an error: the message of its first panic, or the
+/// line it reported the failure on, such as a parse error's first line
+pub fn failure_message(stderr: &str) -> String {
+    match panics(stderr).into_iter().next() {
+        Some(panic) => panic.message,
+        None => stderr
+            .lines()
+            .find(|l| l.starts_with("Could not "))
+            .unwrap_or("Exited with an error")
+            .to_string(),
+    }
+}
+
+/// The report a day prints with `--json`. Solutions may still print their own
+/// output so the report is the last line that parses
+pub fn parse_run_report(stdout: &str) -> Option<RunReport> {
+    stdout
+        .lines()
+        .rev()
+        .find_map(|l| serde_json::from_str(l).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_report_after_other_output() {
+        let stdout = concat!(
+            "Shortest sequence for [true] is [Button]\n",
+            r#"{"day":10,"parse":{"elapsed_ns":5},"#,
+            r#""part1":{"elapsed_ns":7,"answer":{"kind":"integer","value":"7"},"error":null},"#,
+            r#""part2":{"elapsed_ns":9,"answer":null,"error":"Not solved yet"}}"#,
+            "\n"
+        );
+        let report = parse_run_report(stdout).unwrap();
+        assert_eq!(report.day, 10);
+        assert_eq!(report.part1.answer.unwrap().canonical(), "7");
+        assert_eq!(report.part2.error.as_deref(), Some("Not solved yet"));
+    }
+
+    #[test]
+    fn budget_exits_count_as_timeouts() {
+        let success = |code: i32| {
+            let mut command = Command::new("sh");
+            command.args(["-c", &format!("exit {}", code)]);
+            run_with_timeout(&mut command, Duration::from_secs(10))
+                .unwrap()
+                .success
+        };
+        assert_eq!(success(0), Some(true));
+        assert_eq!(success(1), Some(false));
+        assert_eq!(success(EXIT_TIMED_OUT), None);
+    }
+
+    #[test]
+    fn extracts_failure_message() {
+        let stderr =
+            "thread 'main' (12) panicked at src/main.rs:3:5:\nInvalid character\nnote: ...";
+        assert_eq!(failure_message(stderr), "Invalid character");
+        assert_eq!(
+            panics(stderr),
+            [Panic {
+                location: "src/main.rs:3:5".to_string(),
+                message: "Invalid character".to_string()
+            }]
+        );
+
+        let stderr = "Could not parse input: line 2, column 1: expected a distance\n2 | L\n  | ^\n";
+        assert_eq!(
+            failure_message(stderr),
+            "Could not parse input: line 2, column 1: expected a distance"
+        );
+        assert_eq!(failure_message(""), "Exited with an error");
+    }
+}
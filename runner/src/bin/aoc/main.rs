@@ -0,0 +1,227 @@
+mod cross_check;
+mod days;
+mod minimise;
+mod report;
+mod run_all;
+mod watch;
+
+use std::{path::PathBuf, thread, time::Duration};
+
+use clap::{Parser, Subcommand};
+
+use crate::{
+    cross_check::{CrossCheckOptions, cross_check},
+    minimise::{MinimiseOptions, Predicate, minimise},
+    report::{ReportOptions, report},
+    run_all::{RunAllOptions, run_all},
+    watch::{WatchOptions, watch},
+};
+
+/// Tools that work across every day's solution. Run from the runner crate
+#[derive(Debug, Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Build and run every day in parallel and summarise the results
+    All {
+        /// Seconds a day may run before it is killed
+        #[arg(long, default_value_t = 60)]
+        timeout: u64,
+
+        /// Days run at the same time, defaults to the number of CPUs
+        #[arg(long)]
+        jobs: Option<usize>,
+
+        /// Only run these days
+        #[arg(long, value_delimiter = ',')]
+        days: Vec<usize>,
+
+        /// Puzzle profile whose inputs to use
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// Print the answers and timings of every day as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Rebuild and re-run a day whenever its source, fixtures or input change
+    Watch {
+        /// The day to watch
+        day: usize,
+
+        /// Seconds a run may take before it is killed
+        #[arg(long, default_value_t = 60)]
+        timeout: u64,
+
+        /// Puzzle profile whose input to use
+        #[arg(long)]
+        profile: Option<String>,
+    },
+    /// Reduce an input a day misbehaves on to a small fixture that still
+    /// reproduces it. By default the day must fail the same way throughout
+    Minimise {
+        /// The day that misbehaves
+        day: usize,
+
+        /// The input it misbehaves on
+        input: PathBuf,
+
+        /// Arguments for a second run of the day whose answers are taken as
+        /// correct, keeping inputs where the two disagree. Such as
+        /// `--variant NAME` to check the day against one of its variants
+        #[arg(long, value_name = "ARGS", allow_hyphen_values = true)]
+        reference_args: Option<String>,
+
+        /// Shell command that succeeds for inputs worth keeping, given the
+        /// input on stdin and its path in AOC_INPUT
+        #[arg(long, value_name = "COMMAND", conflicts_with = "reference_args")]
+        check: Option<String>,
+
+        /// Only consider this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Name of the fixture to save the reduced input as
+        #[arg(long, default_value = "minimised")]
+        name: String,
+
+        /// Seconds a run may take before it is killed
+        #[arg(long, default_value_t = 60)]
+        timeout: u64,
+    },
+    /// Compare our answers on every cached input with those of external
+    /// reference solvers, such as teammates' solutions in other languages
+    CrossCheck {
+        /// TOML file of reference solvers, each a table with a `command` run
+        /// through `sh -c` from the file's directory. The input is given on
+        /// stdin and its path, the day and the part in AOC_INPUT, AOC_DAY and
+        /// AOC_PART. Optional `days` and `parts` lists limit what it solves
+        #[arg(long, default_value = "../references.toml")]
+        config: PathBuf,
+
+        /// Only check these days
+        #[arg(long, value_delimiter = ',')]
+        days: Vec<usize>,
+
+        /// Only check the inputs of these profiles
+        #[arg(long, value_delimiter = ',')]
+        profiles: Vec<String>,
+
+        /// Only run these references
+        #[arg(long, value_delimiter = ',')]
+        references: Vec<String>,
+
+        /// Seconds a run of ours or of a reference may take before it is killed
+        #[arg(long, default_value_t = 60)]
+        timeout: u64,
+    },
+    /// Run every day and summarise the days as a Markdown table of their
+    /// status, stars, verified answers and benchmarked times
+    Report {
+        /// TOML file of the answers the puzzle site accepted for the default
+        /// inputs, a `[dayN]` table of `part1` and `part2` strings per day
+        #[arg(long, default_value = "../answers.toml")]
+        answers: PathBuf,
+
+        /// Write the report to this file rather than printing it
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+
+        /// Prefix of the links to each day's solution, such as the URL of the
+        /// repository's source browser ending in a slash
+        #[arg(long, default_value = "")]
+        link_base: String,
+
+        /// Seconds a day may run before it is killed
+        #[arg(long, default_value_t = 60)]
+        timeout: u64,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::All {
+            timeout,
+            jobs,
+            days,
+            profile,
+            json,
+        } => run_all(&RunAllOptions {
+            timeout: Duration::from_secs(timeout),
+            jobs: jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get())),
+            days,
+            profile,
+            json,
+        }),
+        Command::Watch {
+            day,
+            timeout,
+            profile,
+        } => watch(&WatchOptions {
+            day,
+            timeout: Duration::from_secs(timeout),
+            profile,
+        }),
+        Command::Minimise {
+            day,
+            input,
+            reference_args,
+            check,
+            part,
+            name,
+            timeout,
+        } => minimise(&MinimiseOptions {
+            day,
+            input,
+            predicate: match (reference_args, check) {
+                (Some(args), _) => Predicate::Mismatch {
+                    reference_args: args.split_whitespace().map(str::to_string).collect(),
+                },
+                (None, Some(command)) => Predicate::Command(command),
+                (None, None) => Predicate::Failure,
+            },
+            part: part.map(usize::from),
+            name,
+            timeout: Duration::from_secs(timeout),
+        }),
+        Command::CrossCheck {
+            config,
+            days,
+            profiles,
+            references,
+            timeout,
+        } => cross_check(&CrossCheckOptions {
+            config,
+            days,
+            profiles,
+            references,
+            timeout: Duration::from_secs(timeout),
+        }),
+        Command::Report {
+            answers,
+            output,
+            link_base,
+            timeout,
+        } => report(&ReportOptions {
+            answers,
+            output,
+            link_base,
+            timeout: Duration::from_secs(timeout),
+        }),
+    };
+
+    match result {
+        Ok(true) => {}
+        Ok(false) => std::process::exit(1),
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(2);
+        }
+    }
+}
@@ -0,0 +1,459 @@
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+use fixtures::{Expected, write_fixture};
+use runner::{PartReport, RunReport};
+
+use crate::days::{
+    Day, Panic, Run, build_day, failure_message, find_day, panics, parse_run_report, run_day,
+};
+
+#[derive(Debug)]
+pub struct MinimiseOptions {
+    pub day: usize,
+    pub input: PathBuf,
+    pub predicate: Predicate,
+    /// Only consider this part when checking the predicate
+    pub part: Option<usize>,
+    /// Name of the fixture the reduced input is saved as
+    pub name: String,
+    pub timeout: Duration,
+}
+
+/// What makes an input worth keeping while reducing it
+#[derive(Debug)]
+pub enum Predicate {
+    /// The day fails the same way it did on the original input: a panic at
+    /// the same place, the same parse or part error or a timeout. Numbers and
+    /// quoted text in the messages are ignored as they often come from the
+    /// input
+    Failure,
+    /// The day's answers differ from a second run given these extra arguments
+    Mismatch { reference_args: Vec<String> },
+    /// A shell command given the input on stdin and its path in `AOC_INPUT`
+    /// exits successfully
+    Command(String),
+}
+
+/// Reduce an input that makes a day misbehave to a small one that still
+/// does, and save it as a fixture of the day
+pub fn minimise(options: &MinimiseOptions) -> Result<bool, String> {
+    let day = find_day(options.day)?;
+    let input = std::fs::read_to_string(&options.input)
+        .map_err(|e| format!("Could not read \"{}\": {}", options.input.display(), e))?;
+
+    eprintln!("Building day{}", day.number);
+    let executable = build_day(&day)?;
+    let mut checker = Checker {
+        executable,
+        day: &day,
+        options,
+        path: std::env::temp_dir().join(format!(
+            "aoc-minimise-day{}-{}.txt",
+            day.number,
+            std::process::id()
+        )),
+        failure: None,
+        runs: 0,
+    };
+
+    if let Predicate::Failure = options.predicate {
+        let run = checker.run(&input, &[])?;
+        checker.failure = Some(failure(&run, options.part).ok_or(
+            "The day does not fail on this input, give a reference or a check command instead",
+        )?);
+    } else if !checker.interesting(&input)? {
+        return Err("The predicate does not hold for the original input".to_string());
+    }
+
+    let reduced = reduce(&input, |candidate| checker.interesting(candidate))?;
+
+    let expected = match &options.predicate {
+        Predicate::Mismatch { reference_args } => {
+            let run = checker.run(&reduced, reference_args)?;
+            let report = parse_run_report(&run.stdout).ok_or("Reference printed no report")?;
+            Expected {
+                part1: answer(&report.part1, 1, options.part),
+                part2: answer(&report.part2, 2, options.part),
+            }
+        }
+        _ => Expected::default(),
+    };
+    let _ = std::fs::remove_file(&checker.path);
+
+    let comment = format!(
+        "Minimised from {} by `aoc minimise`\n{}",
+        options.input.display(),
+        match (&options.predicate, &checker.failure) {
+            (Predicate::Failure, Some(failure)) => format!("Fails with: {}", failure),
+            (Predicate::Mismatch { .. }, _) => "Expected answers are the reference's".to_string(),
+            (_, _) => "Fill in the expected answers once known".to_string(),
+        }
+    );
+    let path = write_fixture(
+        day.number,
+        &options.name,
+        &reduced,
+        &expected,
+        Some(&comment),
+    )?;
+
+    println!(
+        "Reduced {} lines to {} in {} runs, saved as {}",
+        input.lines().count(),
+        reduced.lines().count(),
+        checker.runs,
+        path.display()
+    );
+    Ok(true)
+}
+
+struct Checker<'a> {
+    executable: PathBuf,
+    day: &'a Day,
+    options: &'a MinimiseOptions,
+    /// Where candidates are written for the day to read
+    path: PathBuf,
+    /// How the original input failed, for `Predicate::Failure`
+    failure: Option<String>,
+    runs: usize,
+}
+
+impl Checker<'_> {
+    fn run(&mut self, input: &str, extra_args: &[String]) -> Result<Run, String> {
+        self.write_candidate(input)?;
+        let mut args = vec![
+            "--input".to_string(),
+            self.path.display().to_string(),
+            "--json".to_string(),
+            "--quiet".to_string(),
+        ];
+        args.extend(extra_args.iter().cloned());
+        run_day(&self.executable, self.day, &args, self.options.timeout)
+    }
+
+    fn write_candidate(&mut self, input: &str) -> Result<(), String> {
+        self.runs += 1;
+        std::fs::write(&self.path, input)
+            .map_err(|e| format!("Could not write \"{}\": {}", self.path.display(), e))
+    }
+
+    fn interesting(&mut self, input: &str) -> Result<bool, String> {
+        let part = self.options.part;
+        match &self.options.predicate {
+            Predicate::Failure => {
+                let run = self.run(input, &[])?;
+                Ok(failure(&run, part) == self.failure)
+            }
+            Predicate::Mismatch { reference_args } => {
+                let run = self.run(input, &[])?;
+                let reference = self.run(input, reference_args)?;
+                let reports = (
+                    parse_run_report(&run.stdout),
+                    parse_run_report(&reference.stdout),
+                );
+                Ok(match reports {
+                    (Some(run), Some(reference)) => mismatch(&run, &reference, part),
+                    _ => false,
+                })
+            }
+            Predicate::Command(command) => {
+                self.write_candidate(input)?;
+                check_command(command, &self.path, self.day)
+            }
+        }
+    }
+}
+
+/// How a run failed, if it did, as a string that stays the same between
+/// inputs failing for the same reason
+fn failure(run: &Run, part: Option<usize>) -> Option<String> {
+    let panics = panics(&run.stderr);
+    match run.success {
+        None => Some("timed out".to_string()),
+        Some(false) => Some(match panics.first() {
+            Some(panic) => panic_signature(panic),
+            None => mask_values(&failure_message(&run.stderr)),
+        }),
+        Some(true) => {
+            let report = parse_run_report(&run.stdout)?;
+            parts(&report, part).find_map(|(n, p)| {
+                let error = p.error.as_ref()?;
+                // Parts that panicked report the payload, the location is in
+                // stderr
+                let panic = error
+                    .strip_prefix("Panicked: ")
+                    .and_then(|message| panics.iter().find(|p| p.message == message));
+                Some(match panic {
+                    Some(panic) => format!("part {}: {}", n, panic_signature(panic)),
+                    None => format!("part {}: {}", n, mask_values(error)),
+                })
+            })
+        }
+    }
+}
+
+fn panic_signature(panic: &Panic) -> String {
+    format!(
+        "panicked at {}: {}",
+        panic.location,
+        mask_values(&panic.message)
+    )
+}
+
+/// The message with its numbers and quoted text replaced by placeholders,
+/// as they usually come from the input and change as it is reduced
+fn mask_values(message: &str) -> String {
+    let mut masked = String::new();
+    let mut chars = message.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '0'..='9' => {
+                while chars.next_if(char::is_ascii_digit).is_some() {}
+                masked.push('N');
+            }
+            '"' => {
+                for c in chars.by_ref() {
+                    if c == '"' {
+                        break;
+                    }
+                }
+                masked.push_str("\"…\"");
+            }
+            c => masked.push(c),
+        }
+    }
+    masked
+}
+
+fn mismatch(run: &RunReport, reference: &RunReport, part: Option<usize>) -> bool {
+    parts(run, part)
+        .zip(parts(reference, part))
+        .any(|((_, a), (_, b))| match (&a.answer, &b.answer) {
+            (Some(a), Some(b)) => a != b,
+            _ => false,
+        })
+}
+
+fn parts(report: &RunReport, only: Option<usize>) -> impl Iterator<Item = (usize, &PartReport)> {
+    [(1, &report.part1), (2, &report.part2)]
+        .into_iter()
+        .filter(move |(n, _)| only.is_none_or(|p| p == *n))
+}
+
+fn answer(report: &PartReport, part: usize, only: Option<usize>) -> Option<String> {
+    if only.is_some_and(|p| p != part) {
+        return None;
+    }
+    report.answer.as_ref().map(|a| a.canonical())
+}
+
+fn check_command(command: &str, input: &Path, day: &Day) -> Result<bool, String> {
+    let stdin = std::fs::File::open(input).map_err(|e| e.to_string())?;
+    let status = Command::new("sh")
+        .args(["-c", command])
+        .env("AOC_INPUT", input)
+        .current_dir(&day.dir)
+        .stdin(stdin)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map_err(|e| format!("Could not run \"{}\": {}", command, e))?;
+    Ok(status.success())
+}
+
+/// Reduce the input a section at a time, then a line at a time within each
+/// section and a column at a time within sections that are grids, until
+/// nothing more can be removed
+fn reduce(
+    input: &str,
+    mut interesting: impl FnMut(&str) -> Result<bool, String>,
+) -> Result<String, String> {
+    let mut sections = split_sections(input);
+    loop {
+        let before = join_sections(&sections).len();
+
+        sections = ddmin(sections, |s| interesting(&join_sections(s)))?;
+        for i in 0..sections.len() {
+            let reduced = ddmin(sections[i].clone(), |lines| {
+                let mut candidate = sections.clone();
+                candidate[i] = lines.to_vec();
+                interesting(&join_sections(&candidate))
+            })?;
+            sections[i] = reduced;
+
+            if let Some(width) = grid_width(&sections[i]) {
+                let columns = ddmin((0..width).collect(), |columns| {
+                    let mut candidate = sections.clone();
+                    candidate[i] = keep_columns(&sections[i], columns);
+                    interesting(&join_sections(&candidate))
+                })?;
+                sections[i] = keep_columns(&sections[i], &columns);
+            }
+        }
+
+        let after = join_sections(&sections).len();
+        if after == before {
+            return Ok(join_sections(&sections));
+        }
+    }
+}
+
+/// Delta debugging: remove ever smaller chunks of the items as long as the
+/// remainder stays interesting. At least one item is always kept
+fn ddmin<T: Clone>(
+    mut items: Vec<T>,
+    mut interesting: impl FnMut(&[T]) -> Result<bool, String>,
+) -> Result<Vec<T>, String> {
+    let mut chunks = 2;
+    while items.len() >= 2 {
+        let chunk_size = items.len().div_ceil(chunks);
+        let mut removed = false;
+        for start in (0..items.len()).step_by(chunk_size) {
+            let end = (start + chunk_size).min(items.len());
+            let complement = [&items[..start], &items[end..]].concat();
+            if interesting(&complement)? {
+                items = complement;
+                chunks = (chunks - 1).max(2);
+                removed = true;
+                break;
+            }
+        }
+
+        if !removed {
+            if chunks >= items.len() {
+                break;
+            }
+            chunks = (chunks * 2).min(items.len());
+        }
+    }
+    Ok(items)
+}
+
+/// The width of a section whose lines all have the same length, as a grid
+/// does, if it has more than one line and column
+fn grid_width(lines: &[String]) -> Option<usize> {
+    let width = lines.first()?.chars().count();
+    let rectangular = lines.iter().all(|l| l.chars().count() == width);
+    (rectangular && lines.len() > 1 && width > 1).then_some(width)
+}
+
+fn keep_columns(lines: &[String], columns: &[usize]) -> Vec<String> {
+    lines
+        .iter()
+        .map(|line| {
+            let chars = line.chars().collect::<Vec<_>>();
+            columns.iter().map(|c| chars[*c]).collect()
+        })
+        .collect()
+}
+
+fn split_sections(input: &str) -> Vec<Vec<String>> {
+    let mut sections = vec![Vec::new()];
+    for line in input.lines() {
+        match sections.last_mut() {
+            Some(section) if line.trim().is_empty() => {
+                if !section.is_empty() {
+                    sections.push(Vec::new());
+                }
+            }
+            Some(section) => section.push(line.to_string()),
+            None => unreachable!("there is always a section"),
+        }
+    }
+    sections.retain(|s| !s.is_empty());
+    sections
+}
+
+fn join_sections(sections: &[Vec<String>]) -> String {
+    let mut joined = sections
+        .iter()
+        .map(|s| s.join("\n"))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    joined.push('\n');
+    joined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ddmin_keeps_only_needed_items() {
+        let items = (0..40).collect::<Vec<_>>();
+        let reduced = ddmin(items, |c| Ok(c.contains(&3) && c.contains(&27))).unwrap();
+        assert_eq!(reduced, [3, 27]);
+    }
+
+    #[test]
+    fn reduces_sections_and_lines() {
+        let input = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n";
+        let reduced = reduce(input, |c| Ok(c.contains("12-18") && c.contains("\n17"))).unwrap();
+        assert_eq!(reduced, "12-18\n\n17\n");
+    }
+
+    #[test]
+    fn reduces_grid_rows_and_columns() {
+        let input = "....\n.#..\n..#.\n....\n";
+        let reduced = reduce(input, |c| Ok(c.matches('#').count() == 2)).unwrap();
+        assert_eq!(reduced, "#.\n.#\n");
+    }
+
+    fn failed_run(stdout: &str, stderr: &str) -> Run {
+        Run {
+            stdout: stdout.to_string(),
+            stderr: stderr.to_string(),
+            elapsed: Duration::ZERO,
+            success: Some(!stdout.is_empty()),
+        }
+    }
+
+    #[test]
+    fn failures_match_whatever_values_they_mention() {
+        let panicked = |bank: &str| {
+            failed_run(
+                "",
+                &format!(
+                    "thread 'main' (7) panicked at src/lib.rs:83:32:\nbank {} is too short\n",
+                    bank
+                ),
+            )
+        };
+        assert_eq!(
+            failure(&panicked("\"818\" of 3"), None),
+            Some("panicked at src/lib.rs:83:32: bank \"…\" of N is too short".to_string())
+        );
+        assert_eq!(
+            failure(&panicked("\"818\" of 3"), None),
+            failure(&panicked("\"9\" of 1"), None)
+        );
+
+        let part_error = |error: &str| {
+            failed_run(
+                &format!(
+                    r#"{{"day":3,"parse":{{"elapsed_ns":1}},"part1":{{"elapsed_ns":1,"answer":null,"error":"{}"}},"part2":{{"elapsed_ns":1,"answer":null,"error":null}}}}"#,
+                    error
+                ),
+                "",
+            )
+        };
+        assert_eq!(
+            failure(&part_error("Cannot turn on 16 batteries"), None),
+            Some("part 1: Cannot turn on N batteries".to_string())
+        );
+        assert_eq!(
+            failure(&part_error("Cannot turn on 16 batteries"), Some(2)),
+            None
+        );
+    }
+
+    #[test]
+    fn sections_round_trip() {
+        let input = "a\nb\n\nc\n";
+        assert_eq!(join_sections(&split_sections(input)), input);
+    }
+}
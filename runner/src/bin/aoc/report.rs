@@ -0,0 +1,308 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use runner::{BenchReport, PartReport, RunReport, read_report};
+use serde::Deserialize;
+
+use crate::days::{Day, build_day, discover_days, failure_message, parse_run_report, run_day};
+
+const BENCH_JSON: &str = "bench.json";
+
+#[derive(Debug)]
+pub struct ReportOptions {
+    /// Answers accepted by the puzzle site for the default profile's inputs
+    pub answers: PathBuf,
+    /// Write the report here rather than to stdout
+    pub output: Option<PathBuf>,
+    /// Prefix of the links to each day's solution, relative when empty
+    pub link_base: String,
+    pub timeout: Duration,
+}
+
+/// Accepted answers of a day, stored as `[dayN]` tables of `part1` and
+/// `part2` strings. Each answer recorded is a star earned
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct VerifiedAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl VerifiedAnswers {
+    fn part(&self, part: usize) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            _ => self.part2.as_deref(),
+        }
+    }
+
+    fn stars(&self) -> usize {
+        [&self.part1, &self.part2]
+            .iter()
+            .filter(|a| a.is_some())
+            .count()
+    }
+}
+
+/// What a run of the day gave, or why it gave nothing
+#[derive(Debug)]
+enum Outcome {
+    Ran(Box<RunReport>),
+    Failed(String),
+    TimedOut,
+}
+
+#[derive(Debug)]
+struct DayRow {
+    day: usize,
+    outcome: Outcome,
+    verified: VerifiedAnswers,
+    bench: Option<BenchReport>,
+}
+
+/// Run every day on its default input and summarise the days as Markdown,
+/// checking the answers against the verified ones and taking timings from
+/// the saved benchmark results
+pub fn report(options: &ReportOptions) -> Result<bool, String> {
+    let mut verified = load_answers(&options.answers)?;
+
+    let mut rows = Vec::new();
+    for day in discover_days()? {
+        eprintln!("Running day{}", day.number);
+        rows.push(DayRow {
+            day: day.number,
+            outcome: run_one(&day, options.timeout),
+            verified: verified
+                .remove(&format!("day{}", day.number))
+                .unwrap_or_default(),
+            bench: read_report(day.number, BENCH_JSON).and_then(|b| serde_json::from_str(&b).ok()),
+        });
+    }
+
+    let markdown = render(&rows, &options.link_base);
+    match &options.output {
+        Some(path) => {
+            std::fs::write(path, markdown)
+                .map_err(|e| format!("Could not write \"{}\": {}", path.display(), e))?;
+            eprintln!("Report written to \"{}\"", path.display());
+        }
+        None => print!("{}", markdown),
+    }
+
+    Ok(true)
+}
+
+/// The verified answers by day. A missing store just means nothing has been
+/// verified yet
+fn load_answers(path: &Path) -> Result<BTreeMap<String, VerifiedAnswers>, String> {
+    let answers = match std::fs::read_to_string(path) {
+        Ok(answers) => answers,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            eprintln!("No verified answers in \"{}\"", path.display());
+            return Ok(BTreeMap::new());
+        }
+        Err(err) => return Err(format!("Could not read \"{}\": {}", path.display(), err)),
+    };
+
+    toml::from_str(&answers).map_err(|e| format!("Could not parse \"{}\": {}", path.display(), e))
+}
+
+fn run_one(day: &Day, timeout: Duration) -> Outcome {
+    let args = ["--json", "--quiet"].map(str::to_string);
+    let run = match build_day(day).and_then(|exe| run_day(&exe, day, &args, timeout)) {
+        Ok(run) => run,
+        Err(err) => return Outcome::Failed(err),
+    };
+
+    match run.success {
+        None => Outcome::TimedOut,
+        Some(false) => Outcome::Failed(failure_message(&run.stderr)),
+        Some(true) => match parse_run_report(&run.stdout) {
+            Some(report) => Outcome::Ran(Box::new(report)),
+            None => Outcome::Failed("No report printed".to_string()),
+        },
+    }
+}
+
+fn render(rows: &[DayRow], link_base: &str) -> String {
+    let stars = rows.iter().map(|r| r.verified.stars()).sum::<usize>();
+
+    let mut out = String::new();
+    writeln!(out, "# Advent of Code 2025").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "**Stars:** {} of {}", stars, rows.len() * 2).unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "| Day | Status | Stars | Part 1 | Part 1 time | Part 2 | Part 2 time |"
+    )
+    .unwrap();
+    writeln!(out, "|---|---|---|---|--:|---|--:|").unwrap();
+
+    for row in rows {
+        let stars = format!(
+            "{}{}",
+            "★".repeat(row.verified.stars()),
+            "☆".repeat(2 - row.verified.stars())
+        );
+        let [part1, part2] = [1, 2].map(|p| verification(row, p));
+        let [time1, time2] = ["part1", "part2"].map(|p| median(row.bench.as_ref(), p));
+        writeln!(
+            out,
+            "| [Day {}]({}day{}/src/lib.rs) | {} | {} | {} | {} | {} | {} |",
+            row.day,
+            link_base,
+            row.day,
+            status(&row.outcome),
+            stars,
+            part1,
+            time1,
+            part2,
+            time2
+        )
+        .unwrap();
+    }
+
+    out
+}
+
+fn status(outcome: &Outcome) -> String {
+    match outcome {
+        Outcome::Ran(report) => match [&report.part1, &report.part2].map(|p| p.answer.is_some()) {
+            [true, true] => "solved".to_string(),
+            [true, false] => "part 1 solved".to_string(),
+            [false, true] => "part 2 solved".to_string(),
+            [false, false] => "unsolved".to_string(),
+        },
+        Outcome::Failed(reason) => format!("failed: {}", escape(reason)),
+        Outcome::TimedOut => "timed out".to_string(),
+    }
+}
+
+/// Whether the part's answer is the verified one
+fn verification(row: &DayRow, part: usize) -> &'static str {
+    let Outcome::Ran(report) = &row.outcome else {
+        return "-";
+    };
+    let result: &PartReport = match part {
+        1 => &report.part1,
+        _ => &report.part2,
+    };
+
+    match (&result.answer, row.verified.part(part)) {
+        (None, _) => "-",
+        (Some(_), None) => "unverified",
+        (Some(answer), Some(expected)) if answer.matches(expected) => "verified",
+        (Some(_), Some(_)) => "**wrong**",
+    }
+}
+
+fn median(bench: Option<&BenchReport>, phase: &str) -> String {
+    bench
+        .and_then(|b| b.phase(phase))
+        .map(|p| format!("{:.2?}", Duration::from_nanos(p.median_ns)))
+        .unwrap_or("-".to_string())
+}
+
+/// Keep text from breaking out of its table cell
+fn escape(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::{Answer, PhaseStats};
+
+    fn part(answer: Option<Answer>) -> PartReport {
+        PartReport {
+            error: answer.is_none().then(|| "Not solved yet".to_string()),
+            answer,
+            ..PartReport::default()
+        }
+    }
+
+    fn ran(part1: Option<Answer>, part2: Option<Answer>) -> Outcome {
+        Outcome::Ran(Box::new(RunReport {
+            day: 1,
+            parse: Default::default(),
+            part1: part(part1),
+            part2: part(part2),
+        }))
+    }
+
+    #[test]
+    fn parses_answers() {
+        let answers: BTreeMap<String, VerifiedAnswers> = toml::from_str(
+            r#"
+            [day1]
+            part1 = "1092"
+            part2 = "6616"
+
+            [day2]
+            part1 = "42"
+        "#,
+        )
+        .unwrap();
+        assert_eq!(answers["day1"].stars(), 2);
+        assert_eq!(answers["day2"].part(1), Some("42"));
+        assert_eq!(answers["day2"].part(2), None);
+    }
+
+    #[test]
+    fn renders_days() {
+        let rows = [
+            DayRow {
+                day: 1,
+                outcome: ran(Some(Answer::Integer(3)), Some(Answer::Integer(5))),
+                verified: VerifiedAnswers {
+                    part1: Some("3".to_string()),
+                    part2: Some("6".to_string()),
+                },
+                bench: Some(BenchReport {
+                    day: 1,
+                    phases: vec![PhaseStats {
+                        phase: "part1".to_string(),
+                        runs: 10,
+                        min_ns: 1_000,
+                        median_ns: 1_500,
+                        p95_ns: 2_000,
+                        allocations: None,
+                    }],
+                }),
+            },
+            DayRow {
+                day: 2,
+                outcome: ran(Some(Answer::Integer(7)), None),
+                verified: VerifiedAnswers::default(),
+                bench: None,
+            },
+            DayRow {
+                day: 3,
+                outcome: Outcome::Failed("bad | input".to_string()),
+                verified: VerifiedAnswers::default(),
+                bench: None,
+            },
+        ];
+
+        let markdown = render(&rows, "");
+        let lines = markdown.lines().collect::<Vec<_>>();
+        assert_eq!(lines[2], "**Stars:** 2 of 6");
+        assert_eq!(
+            lines[6],
+            "| [Day 1](day1/src/lib.rs) | solved | ★★ | verified | 1.50µs | **wrong** | - |"
+        );
+        assert_eq!(
+            lines[7],
+            "| [Day 2](day2/src/lib.rs) | part 1 solved | ☆☆ | unverified | - | - | - |"
+        );
+        assert_eq!(
+            lines[8],
+            "| [Day 3](day3/src/lib.rs) | failed: bad \\| input | ☆☆ | - | - | - | - |"
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use std::{
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+    time::Duration,
+};
+
+use runner::RunReport;
+use serde::Serialize;
+
+use crate::days::{Day, build_day, discover_days, failure_message, parse_run_report, run_day};
+
+#[derive(Debug)]
+pub struct RunAllOptions {
+    pub timeout: Duration,
+    pub jobs: usize,
+    /// Only run these days, every day when empty
+    pub days: Vec<usize>,
+    pub profile: Option<String>,
+    /// Print the results as JSON rather than a table
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Status {
+    Solved,
+    Failed,
+    TimedOut,
+}
+
+#[derive(Debug, Serialize)]
+struct DayResult {
+    day: usize,
+    status: Status,
+    reason: Option<String>,
+    elapsed_ns: Option<u64>,
+    report: Option<RunReport>,
+}
+
+/// Build and run every day in parallel, printing a summary. Returns whether
+/// every day solved both parts
+pub fn run_all(options: &RunAllOptions) -> Result<bool, String> {
+    let days = discover_days()?
+        .into_iter()
+        .filter(|d| options.days.is_empty() || options.days.contains(&d.number))
+        .collect::<Vec<_>>();
+
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.max(1) {
+            scope.spawn(|| {
+                while let Some(day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let result = run_one(day, options);
+                    eprintln!("day{} {}", result.day, status_label(result.status));
+                    results.lock().unwrap().push(result);
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|r| r.day);
+
+    if options.json {
+        let json = serde_json::to_string_pretty(&results).map_err(|e| e.to_string())?;
+        println!("{}", json);
+    } else {
+        print_summary(&results);
+    }
+
+    Ok(results.iter().all(|r| matches!(r.status, Status::Solved)))
+}
+
+fn run_one(day: &Day, options: &RunAllOptions) -> DayResult {
+    let mut result = DayResult {
+        day: day.number,
+        status: Status::Failed,
+        reason: None,
+        elapsed_ns: None,
+        report: None,
+    };
+
+    let mut args = vec!["--json".to_string()];
+    if let Some(profile) = &options.profile {
+        args.extend(["--profile".to_string(), profile.clone()]);
+    }
+
+    let run =
+        build_day(day).and_then(|executable| run_day(&executable, day, &args, options.timeout));
+    let run = match run {
+        Ok(run) => run,
+        Err(err) => {
+            result.reason = Some(err);
+            return result;
+        }
+    };
+
+    result.elapsed_ns = Some(run.elapsed.as_nanos() as u64);
+    result.report = parse_run_report(&run.stdout);
+
+    match (run.success, &result.report) {
+        (None, _) => result.status = Status::TimedOut,
+        (Some(false), _) => {
+            result.reason = Some(failure_message(&run.stderr));
+        }
+        (Some(true), None) => result.reason = Some("No report printed".to_string()),
+        (Some(true), Some(report)) if report.part1.timed_out || report.part2.timed_out => {
+            result.status = Status::TimedOut;
+        }
+        (Some(true), Some(report)) => {
+            match [&report.part1, &report.part2]
+                .iter()
+                .find_map(|p| p.error.clone())
+            {
+                Some(err) => result.reason = Some(err),
+                None => result.status = Status::Solved,
+            }
+        }
+    }
+
+    result
+}
+
+fn status_label(status: Status) -> &'static str {
+    match status {
+        Status::Solved => "ok",
+        Status::Failed => "failed",
+        Status::TimedOut => "timeout",
+    }
+}
+
+fn print_summary(results: &[DayResult]) {
+    println!(
+        "{:<5}{:<9}{:<20}{:<20}{:>10}",
+        "day", "status", "part 1", "part 2", "time"
+    );
+    for r in results {
+        let [part1, part2] = match &r.report {
+            Some(report) => [&report.part1, &report.part2].map(|p| match (&p.answer, &p.error) {
+                (Some(answer), _) => answer.to_string(),
+                (None, Some(_)) => "failed".to_string(),
+                (None, None) => "-".to_string(),
+            }),
+            None => ["-".to_string(), "-".to_string()],
+        };
+        let elapsed = r
+            .elapsed_ns
+            .map(|e| format!("{:.2?}", Duration::from_nanos(e)))
+            .unwrap_or("-".to_string());
+        println!(
+            "{:<5}{:<9}{:<20}{:<20}{:>10}",
+            r.day,
+            status_label(r.status),
+            part1,
+            part2,
+            elapsed
+        );
+    }
+
+    for r in results {
+        if let Some(reason) = &r.reason {
+            println!("day{}: {}", r.day, reason);
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use fixtures::{Fixture, examples_dir, fixture_names, load_fixture};
+use runner::{PartReport, RunReport};
+
+use crate::days::{Day, build_day, failure_message, find_day, parse_run_report, run_day};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug)]
+pub struct WatchOptions {
+    pub day: usize,
+    pub timeout: Duration,
+    pub profile: Option<String>,
+}
+
+/// Rebuild and re-run a day whenever its source, fixtures or input change.
+/// The examples run first and the real input only once they all pass
+pub fn watch(options: &WatchOptions) -> Result<bool, String> {
+    let day = find_day(options.day)?;
+
+    let mut seen = BTreeMap::new();
+    loop {
+        let files = watched_files(&day);
+        if files != seen {
+            // Editors often write a file in several steps so wait for the
+            // changes to settle before building
+            thread::sleep(POLL_INTERVAL);
+            seen = watched_files(&day);
+
+            println!("== day{}: rebuilding", day.number);
+            run_cycle(&day, options);
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Modification times of every file that affects a run: the crate's source
+/// and manifest, its example fixtures and its cached puzzle inputs
+fn watched_files(day: &Day) -> BTreeMap<PathBuf, SystemTime> {
+    let mut files = BTreeMap::new();
+    collect_files(&day.dir.join("src"), &mut files);
+    collect_files(&examples_dir(day.number), &mut files);
+    for file in ["Cargo.toml", "build.rs"] {
+        collect_files(&day.dir.join(file), &mut files);
+    }
+
+    // Solutions may cache their own artifacts alongside the input so only
+    // the inputs themselves are watched
+    if let Ok(entries) = std::fs::read_dir(day.dir.join("cache")) {
+        for entry in entries.flatten() {
+            if entry.file_name().to_string_lossy().starts_with("input") {
+                collect_files(&entry.path(), &mut files);
+            }
+        }
+    }
+
+    files
+}
+
+fn collect_files(path: &Path, files: &mut BTreeMap<PathBuf, SystemTime>) {
+    let Ok(metadata) = std::fs::metadata(path) else {
+        return;
+    };
+
+    if metadata.is_dir() {
+        if let Ok(entries) = std::fs::read_dir(path) {
+            for entry in entries.flatten() {
+                collect_files(&entry.path(), files);
+            }
+        }
+    } else if let Ok(modified) = metadata.modified() {
+        files.insert(path.to_path_buf(), modified);
+    }
+}
+
+fn run_cycle(day: &Day, options: &WatchOptions) {
+    let executable = match build_day(day) {
+        Ok(executable) => executable,
+        Err(err) => {
+            println!("{}", err);
+            return;
+        }
+    };
+
+    let names = match fixture_names(day.number) {
+        Ok(names) => names,
+        Err(err) => {
+            println!("{}", err);
+            return;
+        }
+    };
+
+    let mut passed = true;
+    for name in names {
+        let result = load_fixture(day.number, &name)
+            .and_then(|fixture| check_example(&executable, day, &fixture, options.timeout));
+        match result {
+            Ok(()) => println!("example {}: ok", name),
+            Err(report) => {
+                passed = false;
+                print!("example {}: FAILED\n{}", name, report);
+            }
+        }
+    }
+
+    if !passed {
+        println!("Skipping the puzzle input until the examples pass");
+        return;
+    }
+
+    let mut args = Vec::new();
+    if let Some(profile) = &options.profile {
+        args.extend(["--profile".to_string(), profile.clone()]);
+    }
+    match run_report(&executable, day, args, options.timeout) {
+        Ok(report) => {
+            for (part, result) in [(1, &report.part1), (2, &report.part2)] {
+                match (&result.answer, &result.error) {
+                    (Some(answer), _) => println!(
+                        "input part {}: {} ({:.2?})",
+                        part,
+                        answer,
+                        Duration::from_nanos(result.phase.elapsed_ns)
+                    ),
+                    (None, Some(err)) => println!("input part {}: {}", part, err),
+                    (None, None) => {}
+                }
+            }
+        }
+        Err(err) => println!("input: {}", err),
+    }
+}
+
+/// Run the day over a fixture, returning a report of every part that did
+/// not produce the expected answer
+fn check_example(
+    executable: &Path,
+    day: &Day,
+    fixture: &Fixture,
+    timeout: Duration,
+) -> Result<(), String> {
+    let mut args = vec!["--input".to_string(), fixture.path.display().to_string()];
+    for (name, value) in &fixture.params {
+        args.extend(["--param".to_string(), format!("{}={}", name, value)]);
+    }
+    let report = run_report(executable, day, args, timeout).map_err(|e| format!("  {}\n", e))?;
+
+    let mut failures = String::new();
+    for (part, expected, result) in [
+        (1, &fixture.expected.part1, &report.part1),
+        (2, &fixture.expected.part2, &report.part2),
+    ] {
+        if let Some(expected) = expected {
+            failures.push_str(&part_failure(part, expected, result));
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures)
+    }
+}
+
+fn part_failure(part: usize, expected: &str, result: &PartReport) -> String {
+    match (&result.answer, &result.error) {
+        (Some(actual), _) if actual.matches(expected) => String::new(),
+        (Some(actual), _) => format!("  part {}: mismatch\n{}", part, actual.diff(expected)),
+        (None, Some(err)) => format!("  part {}: could not complete: {}\n", part, err),
+        (None, None) => format!("  part {}: no answer\n", part),
+    }
+}
+
+fn run_report(
+    executable: &Path,
+    day: &Day,
+    mut args: Vec<String>,
+    timeout: Duration,
+) -> Result<RunReport, String> {
+    args.push("--json".to_string());
+    let run = run_day(executable, day, &args, timeout)?;
+
+    match run.success {
+        None => Err(format!("Timed out after {:?}", timeout)),
+        Some(false) => Err(failure_message(&run.stderr)),
+        Some(true) => parse_run_report(&run.stdout).ok_or("No report printed".to_string()),
+    }
+}
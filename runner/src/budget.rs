@@ -0,0 +1,100 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, RecvTimeoutError},
+    },
+    thread,
+    time::Duration,
+};
+
+/// How long a part may keep running after its budget is spent before the
+/// process is stopped, for parts that never poll their cancellation token
+const GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+/// Exit code used when a part ignores its cancellation token, matching
+/// coreutils' `timeout`
+pub const EXIT_TIMED_OUT: i32 = 124;
+
+/// Set once a part has used up its time budget. Parts poll it through
+/// `Context::check_budget` and stop early when it is set
+#[derive(Debug, Clone, Default)]
+pub struct Cancellation {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Cancellation {
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Run a part with a token that is cancelled once the budget elapses.
+/// Returns whether the budget ran out before the part finished. A part that
+/// is still running a grace period after that ends the process
+pub(crate) fn with_budget<T>(
+    budget: Option<Duration>,
+    f: impl FnOnce(&Cancellation) -> T,
+) -> (T, bool) {
+    let cancellation = Cancellation::default();
+    let Some(budget) = budget else {
+        return (f(&cancellation), false);
+    };
+
+    // Dropping the sender when the part finishes wakes the watchdog
+    let (done, finished) = mpsc::channel::<()>();
+    let watchdog = {
+        let cancellation = cancellation.clone();
+        thread::spawn(move || {
+            if finished.recv_timeout(budget) != Err(RecvTimeoutError::Timeout) {
+                return;
+            }
+            cancellation.cancel();
+
+            if finished.recv_timeout(GRACE_PERIOD) == Err(RecvTimeoutError::Timeout) {
+                eprintln!(
+                    "Part exceeded its time budget of {:?} and did not stop",
+                    budget
+                );
+                std::process::exit(EXIT_TIMED_OUT);
+            }
+        })
+    };
+
+    let result = f(&cancellation);
+    drop(done);
+    let _ = watchdog.join();
+
+    (result, cancellation.is_cancelled())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancels_parts_over_budget() {
+        let (polls, timed_out) = with_budget(Some(Duration::from_millis(10)), |c| {
+            let mut polls = 0;
+            while !c.is_cancelled() {
+                polls += 1;
+                thread::sleep(Duration::from_millis(1));
+            }
+            polls
+        });
+        assert!(polls > 0);
+        assert!(timed_out);
+    }
+
+    #[test]
+    fn leaves_fast_parts_alone() {
+        let (cancelled, timed_out) =
+            with_budget(Some(Duration::from_secs(10)), |c| c.is_cancelled());
+        assert!(!cancelled);
+        assert!(!timed_out);
+    }
+}
@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use clap::{ArgAction, Parser};
+use get_input::DEFAULT_PROFILE;
+
+use crate::bench::BenchOptions;
+
+/// Run a day's solution against its puzzle input
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Read the input from this file instead of the cache, `-` reads stdin
+    #[arg(long, value_name = "PATH", conflicts_with = "profile")]
+    pub input: Option<String>,
+
+    /// Puzzle profile whose input to use, each profile has its own cache
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    pub profile: String,
+
+    /// Use a random input generated from this seed instead of the puzzle input
+    #[arg(long, value_name = "SEED", conflicts_with_all = ["input", "profile"])]
+    pub generate: Option<u64>,
+
+    /// Size of the generated input, what it counts depends on the day
+    #[arg(long, default_value_t = 100, requires = "generate")]
+    pub size: usize,
+
+    /// Override a puzzle parameter, may be given more than once
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    pub params: Vec<(String, i64)>,
+
+    /// Solve the part this variant belongs to with it rather than the day's
+    /// own solution, may be given once per part
+    #[arg(long = "variant", value_name = "NAME")]
+    pub variants: Vec<String>,
+
+    /// Stop each part that runs longer than this many seconds
+    #[arg(long, value_name = "SECS", value_parser = parse_budget)]
+    pub budget: Option<Duration>,
+
+    /// Show debug diagnostics from the solution, twice to show trace as well
+    #[arg(short, long, action = ArgAction::Count, conflicts_with = "quiet")]
+    pub verbose: u8,
+
+    /// Only show errors, without progress or diagnostics
+    #[arg(short, long)]
+    pub quiet: bool,
+
+    /// Print the answers and timings as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// Report allocations, bytes allocated and peak live bytes for each phase
+    #[arg(long)]
+    pub alloc: bool,
+
+    /// Sample each part with a CPU profiler, writing a flamegraph and a pprof
+    /// profile per part to the day's report directory. Needs a build with
+    /// `--features runner/cpu-profile`
+    #[arg(long)]
+    pub cpu_profile: bool,
+
+    /// Save the frames each part records as an image in the day's report
+    /// directory, a PNG for a single frame and an animated GIF for several
+    #[arg(long, conflicts_with_all = ["bench", "repl"])]
+    pub record: bool,
+
+    /// Parse the input and explore it with the day's commands instead of
+    /// printing the answers
+    #[arg(long, conflicts_with_all = ["bench", "json"])]
+    pub repl: bool,
+
+    /// Benchmark parsing, both parts and every variant instead of printing
+    /// the answers
+    #[arg(long, help_heading = "Benchmarking")]
+    pub bench: bool,
+
+    /// Timed runs of each phase
+    #[arg(long, default_value_t = 20, help_heading = "Benchmarking")]
+    pub runs: usize,
+
+    /// Untimed runs of each phase before timing starts
+    #[arg(long, default_value_t = 3, help_heading = "Benchmarking")]
+    pub warmup: usize,
+
+    /// Median slowdown over the baseline, in percent, reported as a regression
+    #[arg(long, default_value_t = 10.0, help_heading = "Benchmarking")]
+    pub threshold: f64,
+
+    /// Save the results as the baseline for later runs to compare against
+    #[arg(long, help_heading = "Benchmarking")]
+    pub save_baseline: bool,
+}
+
+impl Args {
+    pub fn bench_options(&self) -> BenchOptions {
+        BenchOptions {
+            runs: self.runs,
+            warmup: self.warmup,
+            threshold: self.threshold,
+            save_baseline: self.save_baseline,
+            count_allocations: self.alloc,
+            input_label: self
+                .generate
+                .map(|seed| format!("generated-{}-{}", seed, self.size)),
+        }
+    }
+}
+
+fn parse_param(s: &str) -> Result<(String, i64), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or(format!("Expected NAME=VALUE, got \"{}\"", s))?;
+    let value = value
+        .trim()
+        .parse()
+        .map_err(|e| format!("Invalid value for \"{}\": {}", name, e))?;
+    Ok((name.trim().to_string(), value))
+}
+
+fn parse_budget(s: &str) -> Result<Duration, String> {
+    let secs: f64 = s.parse().map_err(|e| format!("Invalid budget: {}", e))?;
+    Duration::try_from_secs_f64(secs).map_err(|e| format!("Invalid budget: {}", e))
+}
@@ -0,0 +1,107 @@
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::{
+    artifacts::ArtifactStore,
+    budget::Cancellation,
+    grid::Grid,
+    image::{Recording, Rgb},
+    params::Params,
+    progress::Progress,
+};
+
+/// Everything a part may need besides the parsed input
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+    params: Params,
+    cancellation: Cancellation,
+    artifacts: Option<ArtifactStore>,
+    show_progress: bool,
+    recording: Option<Recording>,
+}
+
+impl Context {
+    pub fn new(params: Params) -> Self {
+        Self {
+            params,
+            cancellation: Cancellation::default(),
+            artifacts: None,
+            show_progress: false,
+            recording: None,
+        }
+    }
+
+    /// Draw progress counters on stderr when it is a terminal. Off by
+    /// default so tests and benchmarks stay quiet
+    pub fn with_progress(mut self, show_progress: bool) -> Self {
+        self.show_progress = show_progress;
+        self
+    }
+
+    /// Save artifacts between runs in this store. Without one every artifact
+    /// is built from scratch, as when checking example fixtures
+    pub fn with_artifacts(mut self, artifacts: ArtifactStore) -> Self {
+        self.artifacts = Some(artifacts);
+        self
+    }
+
+    /// Keep the frames the parts record in this recording
+    pub(crate) fn with_recording(mut self, recording: Recording) -> Self {
+        self.recording = Some(recording);
+        self
+    }
+
+    /// A copy of this context whose budget checks follow the given token
+    pub(crate) fn with_cancellation(&self, cancellation: Cancellation) -> Self {
+        Self {
+            params: self.params.clone(),
+            cancellation,
+            artifacts: self.artifacts.clone(),
+            show_progress: self.show_progress,
+            recording: self.recording.clone(),
+        }
+    }
+
+    /// Look up a puzzle parameter declared in `Solution::PARAMS`
+    pub fn param<T: TryFrom<i64>>(&self, name: &str) -> Result<T, String> {
+        self.params.get(name)
+    }
+
+    /// Fails once the part has run past its time budget. Cheap enough to
+    /// call on every iteration of a hot loop, use it with `?` to stop early
+    pub fn check_budget(&self) -> Result<(), String> {
+        if self.cancellation.is_cancelled() {
+            Err("Stopped after exceeding the time budget".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// An expensive intermediate structure, loaded from a previous run over
+    /// the same input when possible. Bump the version whenever the structure
+    /// or the code building it changes
+    pub fn artifact<T: Serialize + DeserializeOwned>(
+        &self,
+        name: &str,
+        version: u32,
+        build: impl FnOnce() -> Result<T, String>,
+    ) -> Result<T, String> {
+        match &self.artifacts {
+            Some(artifacts) => artifacts.get_or_build(name, version, build),
+            None => build(),
+        }
+    }
+
+    /// Add a frame to the part's recording, saved as an image by `--record`.
+    /// The frame is only drawn when recording so this is cheap to leave in
+    pub fn record(&self, frame: impl FnOnce() -> Grid<Rgb>) {
+        if let Some(recording) = &self.recording {
+            recording.push(frame);
+        }
+    }
+
+    /// A progress counter for a long-running loop, `total` being the number
+    /// of steps expected or zero when that is not known
+    pub fn progress(&self, label: &str, total: u64) -> Progress {
+        Progress::new(label, total, !self.show_progress)
+    }
+}
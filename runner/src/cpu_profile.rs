@@ -0,0 +1,75 @@
+//! Sampling CPU profiles of each phase, written as a flamegraph and a pprof
+//! protobuf to the day's report directory. The profiler is only built with
+//! the `cpu-profile` feature, for example
+//! `cargo run --release --features runner/cpu-profile -- --cpu-profile`
+
+use std::path::PathBuf;
+
+/// Samples taken per second while profiling
+#[cfg(feature = "cpu-profile")]
+const FREQUENCY: i32 = 997;
+
+/// Whether this build can profile, checked before anything runs
+pub const AVAILABLE: bool = cfg!(feature = "cpu-profile");
+
+#[cfg(feature = "cpu-profile")]
+pub struct CpuProfiler(pprof::ProfilerGuard<'static>);
+
+#[cfg(not(feature = "cpu-profile"))]
+pub struct CpuProfiler;
+
+impl CpuProfiler {
+    #[cfg(feature = "cpu-profile")]
+    pub fn start() -> Result<Self, String> {
+        pprof::ProfilerGuardBuilder::default()
+            .frequency(FREQUENCY)
+            .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+            .build()
+            .map(Self)
+            .map_err(|e| format!("Could not start the profiler: {}", e))
+    }
+
+    #[cfg(not(feature = "cpu-profile"))]
+    pub fn start() -> Result<Self, String> {
+        Err("Built without the cpu-profile feature".to_string())
+    }
+
+    /// Stop profiling and write `<phase>.svg` and `<phase>.pb` to the day's
+    /// report directory, returning their paths
+    #[cfg(feature = "cpu-profile")]
+    pub fn finish(self, day: usize, phase: &str) -> Result<Vec<PathBuf>, String> {
+        use pprof::protos::Message;
+
+        use crate::reports::write_report;
+
+        let report = self
+            .0
+            .report()
+            .build()
+            .map_err(|e| format!("Could not build the profile: {}", e))?;
+        if report.data.is_empty() {
+            return Err("no samples were taken, it finished too quickly".to_string());
+        }
+
+        let mut svg = Vec::new();
+        report
+            .flamegraph(&mut svg)
+            .map_err(|e| format!("Could not draw the flamegraph: {}", e))?;
+        let mut pb = Vec::new();
+        report
+            .pprof()
+            .map_err(|e| format!("Could not encode the profile: {}", e))?
+            .encode(&mut pb)
+            .map_err(|e| format!("Could not encode the profile: {}", e))?;
+
+        Ok(vec![
+            write_report(day, &format!("{}.svg", phase), svg)?,
+            write_report(day, &format!("{}.pb", phase), pb)?,
+        ])
+    }
+
+    #[cfg(not(feature = "cpu-profile"))]
+    pub fn finish(self, _day: usize, _phase: &str) -> Result<Vec<PathBuf>, String> {
+        Ok(Vec::new())
+    }
+}
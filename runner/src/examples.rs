@@ -0,0 +1,76 @@
+use std::fmt::Write;
+
+use fixtures::load_fixture;
+
+use crate::{
+    context::Context,
+    params::Params,
+    solution::{PartResult, Solution},
+};
+
+type Part<S> = fn(&<S as Solution>::Input, &Context) -> PartResult;
+
+/// Run a day's solution and its variants over one of its example fixtures,
+/// panicking with a diff of expected versus actual answers if any part
+/// disagrees
+pub fn check_fixture<S: Solution>(name: &str) {
+    let fixture = match load_fixture(S::DAY, name) {
+        Ok(fixture) => fixture,
+        Err(err) => panic!("{}", err),
+    };
+
+    let mut params = Params::new(S::PARAMS);
+    for (name, value) in &fixture.params {
+        if let Err(err) = params.set(name, *value) {
+            panic!("Fixture \"{}\": {}", fixture.path.display(), err);
+        }
+    }
+    let ctx = Context::new(params);
+
+    let input = match S::parse(&fixture.input) {
+        Ok(input) => input,
+        Err(err) => panic!(
+            "Could not parse fixture \"{}\": {}",
+            fixture.path.display(),
+            err
+        ),
+    };
+
+    let parts: [(usize, &Option<String>, Part<S>); 2] = [
+        (1, &fixture.expected.part1, S::part1),
+        (2, &fixture.expected.part2, S::part2),
+    ];
+
+    let mut report = String::new();
+    let mut failed = false;
+    for (part, expected, solve) in parts {
+        let Some(expected) = expected else {
+            continue;
+        };
+
+        let variants = S::VARIANTS
+            .iter()
+            .filter(|v| v.part == part)
+            .map(|v| (format!("part {} ({})", part, v.name), v.solve));
+        for (label, solve) in std::iter::once((format!("part {}", part), solve)).chain(variants) {
+            match solve(&input, &ctx) {
+                Ok(actual) if actual.matches(expected) => {
+                    writeln!(report, "{}: ok ({})", label, actual).unwrap();
+                }
+                Ok(actual) => {
+                    failed = true;
+                    writeln!(report, "{}: mismatch", label).unwrap();
+                    report.push_str(&actual.diff(expected));
+                }
+                Err(err) => {
+                    failed = true;
+                    writeln!(report, "{}: could not complete: {}", label, err).unwrap();
+                }
+            }
+        }
+    }
+
+    if failed {
+        panic!("Fixture \"{}\" failed\n{}", fixture.path.display(), report);
+    }
+}
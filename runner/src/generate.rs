@@ -0,0 +1,59 @@
+//! Random puzzle inputs for stress-testing solutions beyond the one real
+//! input, see `Solution::generate`
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::solution::Solution;
+
+/// Seeds tried by `check_generator`
+const CHECKED_SEEDS: u64 = 8;
+/// Size of the inputs tried by `check_generator`, small so the tests stay fast
+const CHECKED_SIZE: usize = 20;
+
+/// The random number generator handed to `Solution::generate`. Its output
+/// for a seed does not change between platforms or releases, so a seed is
+/// enough to reproduce an input
+pub type InputRng = ChaCha8Rng;
+
+/// Generate an input for the day from `seed`, or `None` if the day has no
+/// generator
+pub fn generate_input<S: Solution>(seed: u64, size: usize) -> Option<String> {
+    S::generate(&mut InputRng::seed_from_u64(seed), size)
+}
+
+/// Check that the day's generator is reproducible and that every input it
+/// generates parses, panicking with the offending seed otherwise. Days
+/// without a generator pass trivially
+pub fn check_generator<S: Solution>() {
+    for seed in 0..CHECKED_SEEDS {
+        let Some(input) = generate_input::<S>(seed, CHECKED_SIZE) else {
+            return;
+        };
+
+        if generate_input::<S>(seed, CHECKED_SIZE).as_ref() != Some(&input) {
+            panic!("Seed {} generated two different inputs", seed);
+        }
+        if let Err(err) = S::parse(&input) {
+            panic!(
+                "Could not parse the input generated from seed {}: {}\n{}",
+                seed, err, input
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Numbers;
+
+    #[test]
+    fn seeds_reproduce_inputs() {
+        let input = generate_input::<Numbers>(3, 5).unwrap();
+        assert_eq!(input.lines().count(), 5);
+        assert_eq!(generate_input::<Numbers>(3, 5), Some(input.clone()));
+        assert_ne!(generate_input::<Numbers>(4, 5), Some(input));
+        check_generator::<Numbers>();
+    }
+}
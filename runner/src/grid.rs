@@ -0,0 +1,403 @@
+//! Rectangular grids of tiles, as many puzzle inputs are. Points are signed
+//! so that stepping off an edge gives a position outside the grid rather
+//! than an overflow, and every lookup checks the bounds. Cells are their
+//! unsigned counterpart for indexing rows and columns, converted to and from
+//! points with checks rather than casts
+
+use std::{
+    num::TryFromIntError,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Sub},
+};
+
+use crate::{parse, parse_error::ParseError};
+
+/// A position on a grid, `x` counting columns to the right and `y` rows
+/// down from the top left corner
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const UP: Point = Point::new(0, -1);
+    pub const DOWN: Point = Point::new(0, 1);
+    pub const LEFT: Point = Point::new(-1, 0);
+    pub const RIGHT: Point = Point::new(1, 0);
+
+    /// Steps to the four neighbours sharing an edge, clockwise from up
+    pub const ORTHOGONAL: [Point; 4] = [Self::UP, Self::RIGHT, Self::DOWN, Self::LEFT];
+
+    /// Steps to the eight neighbours sharing an edge or a corner, clockwise
+    /// from up
+    pub const ADJACENT: [Point; 8] = [
+        Self::UP,
+        Point::new(1, -1),
+        Self::RIGHT,
+        Point::new(1, 1),
+        Self::DOWN,
+        Point::new(-1, 1),
+        Self::LEFT,
+        Point::new(-1, -1),
+    ];
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Steps needed to get from one point to the other moving orthogonally
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl TryFrom<Cell> for Point {
+    type Error = TryFromIntError;
+
+    /// The point of a cell, failing if it is too far out for an `i64`
+    fn try_from(cell: Cell) -> Result<Self, Self::Error> {
+        Ok(Self::new(cell.x.try_into()?, cell.y.try_into()?))
+    }
+}
+
+/// A position on a grid by its column `x` and row `y`, which unlike a
+/// [`Point`] cannot be left or above the top left corner
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cell {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Cell {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+impl TryFrom<Point> for Cell {
+    type Error = TryFromIntError;
+
+    /// The cell of a point, failing if it is left or above the top left corner
+    fn try_from(p: Point) -> Result<Self, Self::Error> {
+        Ok(Self::new(p.x.try_into()?, p.y.try_into()?))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Point {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+/// Tiles stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    tiles: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A grid of the given tiles, row by row. Panics if they do not fill
+    /// whole rows
+    pub fn from_tiles(width: usize, tiles: Vec<T>) -> Self {
+        assert!(
+            width > 0 && tiles.len().is_multiple_of(width),
+            "{} tiles do not make rows of {}",
+            tiles.len(),
+            width
+        );
+        Self {
+            height: tiles.len() / width,
+            width,
+            tiles,
+        }
+    }
+
+    /// Parse a rectangular grid of characters, `tile` turning a character
+    /// into a tile or rejecting it as not what was `expected`
+    pub fn parse(
+        source: &str,
+        s: &str,
+        tile: impl Fn(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let (tiles, width) = parse::char_grid(source, s, tile, expected)?;
+        if width == 0 {
+            return Err(ParseError::at(source, s, "a row of tiles"));
+        }
+        Ok(Self::from_tiles(width, tiles))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.cell(p).is_some()
+    }
+
+    /// The cell of the point, `None` off the grid
+    pub fn cell(&self, p: Point) -> Option<Cell> {
+        Cell::try_from(p)
+            .ok()
+            .filter(|c| c.x < self.width && c.y < self.height)
+    }
+
+    /// The point of the cell, `None` off the grid
+    pub fn point(&self, cell: Cell) -> Option<Point> {
+        (cell.x < self.width && cell.y < self.height).then(|| on_grid(cell))
+    }
+
+    fn offset(&self, p: Point) -> Option<usize> {
+        self.cell(p).map(|c| c.y * self.width + c.x)
+    }
+
+    /// The tile at the point, `None` off the grid
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.offset(p).map(|i| &self.tiles[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.tiles[i])
+    }
+
+    /// Every cell of the grid, row by row
+    pub fn cells(&self) -> impl Iterator<Item = Cell> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Cell::new(x, y)))
+    }
+
+    /// Every point of the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        self.cells().map(on_grid)
+    }
+
+    /// Every tile along with its point, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.tiles)
+    }
+
+    /// The up to four neighbours of the point sharing an edge with it
+    pub fn orthogonal(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(p, &Point::ORTHOGONAL)
+    }
+
+    /// The up to eight neighbours of the point sharing an edge or a corner
+    /// with it
+    pub fn adjacent(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(p, &Point::ADJACENT)
+    }
+
+    fn neighbours(&self, p: Point, steps: &'static [Point]) -> impl Iterator<Item = Point> + '_ {
+        steps
+            .iter()
+            .map(move |step| p + *step)
+            .filter(|n| self.contains(*n))
+    }
+
+    /// The tiles of row `y`, from the left. Panics if it is off the grid
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.tiles[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Every row from the top
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.tiles.chunks(self.width)
+    }
+
+    /// The tiles of column `x`, from the top. Panics if it is off the grid
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is off the grid", x);
+        self.tiles.iter().skip(x).step_by(self.width)
+    }
+
+    /// The tiles in the rectangle with `min` and `max` as opposite corners,
+    /// both included, row by row. Only the part on the grid is visited
+    pub fn region(&self, min: Point, max: Point) -> impl Iterator<Item = (Point, &T)> {
+        let last = on_grid(Cell::new(self.width, self.height)) - Point::new(1, 1);
+        let (min, max) = (
+            Point::new(min.x.min(max.x).max(0), min.y.min(max.y).max(0)),
+            Point::new(min.x.max(max.x).min(last.x), min.y.max(max.y).min(last.y)),
+        );
+        (min.y..=max.y)
+            .flat_map(move |y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+            .map(|p| (p, &self[p]))
+    }
+
+    /// A grid of the same size with `f` applied to every tile
+    pub fn map<U>(&self, f: impl Fn(Point, &T) -> U) -> Grid<U> {
+        Grid::from_tiles(self.width, self.iter().map(|(p, t)| f(p, t)).collect())
+    }
+
+    /// Draw the grid as text, a line per row
+    pub fn render(&self, tile: impl Fn(Point, &T) -> char) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for (cell, t) in self.cells().zip(&self.tiles) {
+            out.push(tile(on_grid(cell), t));
+            if cell.x == self.width - 1 {
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid with every tile set to `tile`
+    pub fn new(width: usize, height: usize, tile: T) -> Self {
+        Self::from_tiles(width, vec![tile; width * height])
+    }
+}
+
+/// The point of a cell of a grid, or just past its far edges. The tiles are
+/// stored in a `Vec`, whose length fits an `isize`, so this cannot overflow
+fn on_grid(cell: Cell) -> Point {
+    Point::new(cell.x as i64, cell.y as i64)
+}
+
+impl<T> Index<Cell> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, cell: Cell) -> &T {
+        assert!(
+            cell.x < self.width && cell.y < self.height,
+            "{:?} is off the grid",
+            cell
+        );
+        &self.tiles[cell.y * self.width + cell.x]
+    }
+}
+
+impl<T> IndexMut<Cell> for Grid<T> {
+    fn index_mut(&mut self, cell: Cell) -> &mut T {
+        assert!(
+            cell.x < self.width && cell.y < self.height,
+            "{:?} is off the grid",
+            cell
+        );
+        &mut self.tiles[cell.y * self.width + cell.x]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{:?} is off the grid", p))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{:?} is off the grid", p))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rolls() -> Grid<bool> {
+        let input = "..@\n@@.\n.@@\n";
+        Grid::parse(input, input, |c| (c != 'x').then_some(c == '@'), "a roll").unwrap()
+    }
+
+    #[test]
+    fn parses_and_renders() {
+        let grid = rolls();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert!(grid[Point::new(2, 0)]);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(
+            grid.render(|_, roll| if *roll { '@' } else { '.' }),
+            "..@\n@@.\n.@@\n"
+        );
+
+        let input = "..x\n";
+        let err = Grid::parse(input, input, |c| (c == '.').then_some(()), "a dot").unwrap_err();
+        assert_eq!(err.span().unwrap().columns, 2..3);
+    }
+
+    #[test]
+    fn neighbours_stay_on_the_grid() {
+        let grid = rolls();
+        let corner = grid.orthogonal(Point::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, [Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.adjacent(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.adjacent(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.adjacent(Point::new(2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn views_rows_columns_and_regions() {
+        let grid = Grid::from_tiles(3, (0..6).collect());
+        assert_eq!(grid.row(1), [3, 4, 5]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [1, 4]);
+
+        let region = grid.region(Point::new(5, -1), Point::new(1, 0));
+        assert_eq!(
+            region.collect::<Vec<_>>(),
+            [(Point::new(1, 0), &1), (Point::new(2, 0), &2)]
+        );
+    }
+
+    #[test]
+    fn points_step_and_measure() {
+        let mut p = Point::new(2, 3);
+        p += Point::LEFT * 3;
+        assert_eq!(p, Point::new(-1, 3));
+        assert_eq!(p - Point::DOWN, Point::new(-1, 2));
+        assert_eq!(p.manhattan(Point::new(1, 1)), 4);
+    }
+
+    #[test]
+    fn converts_between_points_and_cells() {
+        assert_eq!(Cell::try_from(Point::new(2, 3)), Ok(Cell::new(2, 3)));
+        assert!(Cell::try_from(Point::new(-1, 3)).is_err());
+        assert_eq!(Point::try_from(Cell::new(2, 3)), Ok(Point::new(2, 3)));
+        assert!(Point::try_from(Cell::new(usize::MAX, 0)).is_err());
+
+        let mut grid = rolls();
+        assert_eq!(grid.cell(Point::new(2, 0)), Some(Cell::new(2, 0)));
+        assert_eq!(grid.cell(Point::new(3, 0)), None);
+        assert_eq!(grid.point(Cell::new(0, 2)), Some(Point::new(0, 2)));
+        assert_eq!(grid.point(Cell::new(0, 3)), None);
+
+        grid[Cell::new(0, 0)] = true;
+        assert!(grid[Point::new(0, 0)]);
+        assert_eq!(grid.cells().nth(4), Some(Cell::new(1, 1)));
+    }
+}
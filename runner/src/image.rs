@@ -0,0 +1,216 @@
+//! Grids drawn as images, a square of pixels per tile. A single frame is
+//! saved as a PNG and a simulation's steps as an animated GIF, see
+//! `--record` and `Context::record`
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use crate::grid::Grid;
+
+/// Red, green and blue of a pixel
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0x0f, 0x0f, 0x23];
+pub const WHITE: Rgb = [0xcc, 0xcc, 0xcc];
+pub const GREY: Rgb = [0x4d, 0x4d, 0x5c];
+pub const RED: Rgb = [0xff, 0x55, 0x55];
+pub const GREEN: Rgb = [0x00, 0xcc, 0x00];
+pub const YELLOW: Rgb = [0xff, 0xff, 0x66];
+
+/// Images are scaled up until their longest side reaches this many pixels
+const MIN_SIDE: usize = 512;
+const MAX_SCALE: usize = 16;
+
+/// Frames kept per part, later ones are dropped to bound the memory used
+const MAX_FRAMES: usize = 500;
+
+/// Hundredths of a second each frame of an animation is shown, the last one
+/// staying longer before it loops
+const FRAME_DELAY: u16 = 10;
+const LAST_FRAME_DELAY: u16 = 200;
+
+/// Pixels per tile side for a grid of this size
+fn scale(width: usize, height: usize) -> usize {
+    (MIN_SIDE / width.max(height).max(1)).clamp(1, MAX_SCALE)
+}
+
+/// Every pixel of the frame scaled up, row by row, as `pixel` gives it
+fn pixels<P: Copy>(frame: &Grid<Rgb>, scale: usize, pixel: impl Fn(Rgb) -> P) -> Vec<P> {
+    let mut pixels = Vec::with_capacity(frame.width() * frame.height() * scale * scale);
+    for row in frame.rows() {
+        let line = row
+            .iter()
+            .flat_map(|tile| std::iter::repeat_n(pixel(*tile), scale))
+            .collect::<Vec<_>>();
+        for _ in 0..scale {
+            pixels.extend_from_slice(&line);
+        }
+    }
+    pixels
+}
+
+/// Encode a frame as a PNG
+pub fn encode_png(frame: &Grid<Rgb>) -> Result<Vec<u8>, String> {
+    let scale = scale(frame.width(), frame.height());
+    let [width, height] = [frame.width(), frame.height()]
+        .map(|side| u32::try_from(side * scale).map_err(|_| "The grid is too large to draw"));
+
+    let mut png = Vec::new();
+    let mut encoder = png::Encoder::new(&mut png, width?, height?);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&pixels(frame, scale, |p| p).concat()))
+        .map_err(|e| format!("Could not encode the PNG: {}", e))?;
+    Ok(png)
+}
+
+/// Encode frames of the same size as a looping animated GIF. GIFs index
+/// their colours so the frames may use 256 colours between them
+pub fn encode_gif(frames: &[Grid<Rgb>]) -> Result<Vec<u8>, String> {
+    let first = frames.first().ok_or("There are no frames to animate")?;
+    if frames
+        .iter()
+        .any(|f| (f.width(), f.height()) != (first.width(), first.height()))
+    {
+        return Err("The frames are not all the same size".to_string());
+    }
+
+    let mut palette = HashMap::new();
+    for (_, colour) in frames.iter().flat_map(|f| f.iter()) {
+        let next = palette.len();
+        palette.entry(*colour).or_insert(next);
+    }
+    if palette.len() > 256 {
+        return Err(format!("{} colours do not fit a GIF's 256", palette.len()));
+    }
+    let mut colours = vec![[0; 3]; palette.len()];
+    for (colour, index) in &palette {
+        colours[*index] = *colour;
+    }
+
+    let scale = scale(first.width(), first.height());
+    let [width, height] = [first.width(), first.height()]
+        .map(|side| u16::try_from(side * scale).map_err(|_| "The grid is too large to animate"));
+    let (width, height) = (width?, height?);
+
+    let error = |e: gif::EncodingError| format!("Could not encode the GIF: {}", e);
+    let mut gif = Vec::new();
+    let mut encoder =
+        gif::Encoder::new(&mut gif, width, height, &colours.concat()).map_err(error)?;
+    encoder.set_repeat(gif::Repeat::Infinite).map_err(error)?;
+    for (i, frame) in frames.iter().enumerate() {
+        let indices = pixels(frame, scale, |colour| palette[&colour] as u8);
+        let mut frame = gif::Frame::from_indexed_pixels(width, height, indices, None);
+        frame.delay = if i + 1 == frames.len() {
+            LAST_FRAME_DELAY
+        } else {
+            FRAME_DELAY
+        };
+        encoder.write_frame(&frame).map_err(error)?;
+    }
+    drop(encoder);
+
+    Ok(gif)
+}
+
+/// Frames recorded by a part, shared by the copies of its context
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Recording(Arc<Mutex<Frames>>);
+
+#[derive(Debug, Default)]
+struct Frames {
+    kept: Vec<Grid<Rgb>>,
+    dropped: usize,
+}
+
+impl Recording {
+    pub(crate) fn push(&self, frame: impl FnOnce() -> Grid<Rgb>) {
+        let mut frames = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        if frames.kept.len() < MAX_FRAMES {
+            frames.kept.push(frame());
+        } else {
+            frames.dropped += 1;
+        }
+    }
+
+    /// The frames recorded so far and how many were dropped, leaving the
+    /// recording empty for the next part
+    pub(crate) fn take(&self) -> (Vec<Grid<Rgb>>, usize) {
+        let mut frames = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        let dropped = std::mem::take(&mut frames.dropped);
+        (std::mem::take(&mut frames.kept), dropped)
+    }
+
+    /// Save a part's frames to the day's report directory, as a PNG when
+    /// there is one and an animated GIF when there are several. `None` when
+    /// the part recorded nothing
+    pub(crate) fn save(&self, day: usize, phase: &str) -> Option<Result<String, String>> {
+        use crate::reports::write_report;
+
+        let (frames, dropped) = self.take();
+        let (file_name, image) = match frames.as_slice() {
+            [] => return None,
+            [frame] => (format!("{}.png", phase), encode_png(frame)),
+            frames => (format!("{}.gif", phase), encode_gif(frames)),
+        };
+
+        let saved = image.and_then(|image| write_report(day, &file_name, image));
+        Some(saved.map(|path| {
+            let mut message = format!("Recording written to \"{}\"", path.display());
+            if dropped > 0 {
+                message += &format!(", without the last {} frames", dropped);
+            }
+            message
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scales_tiles_to_pixels() {
+        let frame = Grid::from_tiles(2, vec![RED, GREEN]);
+        assert_eq!(scale(2, 1), MAX_SCALE);
+        assert_eq!(scale(1024, 3), 1);
+        assert_eq!(pixels(&frame, 2, |p| p), [RED, RED, GREEN, GREEN].repeat(2));
+
+        let png = encode_png(&frame).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    fn animates_frames() {
+        let frames = [RED, GREEN, YELLOW].map(|colour| Grid::new(3, 2, colour));
+        let gif = encode_gif(&frames).unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+
+        let mut decoder = gif::DecodeOptions::new().read_info(gif.as_slice()).unwrap();
+        let mut delays = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            delays.push(frame.delay);
+        }
+        assert_eq!(delays, [FRAME_DELAY, FRAME_DELAY, LAST_FRAME_DELAY]);
+
+        assert_eq!(
+            encode_gif(&[Grid::new(1, 1, RED), Grid::new(2, 1, RED)]),
+            Err("The frames are not all the same size".to_string())
+        );
+    }
+
+    #[test]
+    fn keeps_frames_until_taken() {
+        let recording = Recording::default();
+        for _ in 0..MAX_FRAMES + 2 {
+            recording.clone().push(|| Grid::new(1, 1, BLACK));
+        }
+        let (frames, dropped) = recording.take();
+        assert_eq!((frames.len(), dropped), (MAX_FRAMES, 2));
+        assert_eq!(recording.take().0.len(), 0);
+    }
+}
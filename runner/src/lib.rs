@@ -0,0 +1,49 @@
+mod alloc;
+mod answer;
+mod artifacts;
+mod bench;
+mod budget;
+mod cli;
+mod context;
+mod cpu_profile;
+mod examples;
+mod generate;
+pub mod grid;
+pub mod image;
+mod params;
+pub mod parse;
+mod parse_error;
+mod progress;
+pub mod repl;
+mod reports;
+mod run_report;
+mod runner;
+mod solution;
+#[cfg(test)]
+mod test_support;
+mod variants;
+mod verbosity;
+
+pub use alloc::{AllocStats, measure_allocations};
+pub use answer::Answer;
+pub use artifacts::ArtifactStore;
+pub use bench::{BenchReport, PhaseStats};
+pub use budget::{Cancellation, EXIT_TIMED_OUT};
+pub use context::Context;
+pub use examples::check_fixture;
+pub use generate::{InputRng, check_generator, generate_input};
+pub use params::{Param, Params};
+pub use parse_error::{ParseError, Span, parse_token};
+pub use progress::Progress;
+pub use repl::{CommandResult, ReplCommand};
+pub use reports::read_report;
+pub use run_report::{PartReport, PhaseReport, RunReport};
+pub use runner::run;
+pub use solution::{PartResult, Solution};
+pub use variants::{Variant, check_variants};
+
+/// Solutions log diagnostics through these macros, see `--verbose`
+pub use tracing::{debug, error, info, trace, warn};
+
+/// Generators draw from `InputRng` through `rand::Rng`
+pub use rand;
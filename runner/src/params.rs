@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+
+/// A named puzzle constant, declared by a solution along with the value it
+/// takes for the real input. Examples often use a smaller value which can be
+/// set from the fixture or the command line
+#[derive(Debug, Clone, Copy)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: i64,
+    /// Smallest and largest values the solution can handle, both included
+    pub min: i64,
+    pub max: i64,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, default: i64) -> Self {
+        Self {
+            name,
+            default,
+            min: i64::MIN,
+            max: i64::MAX,
+        }
+    }
+
+    /// Only accept overrides from `min` to `max`, both included
+    pub const fn between(self, min: i64, max: i64) -> Self {
+        Self { min, max, ..self }
+    }
+}
+
+/// The values of every declared parameter for one run
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    values: BTreeMap<&'static str, (Param, i64)>,
+}
+
+impl Params {
+    pub fn new(declared: &[Param]) -> Self {
+        Self {
+            values: declared.iter().map(|p| (p.name, (*p, p.default))).collect(),
+        }
+    }
+
+    /// Override a declared parameter. Overriding something the solution does
+    /// not declare is an error so typos do not go unnoticed, as is a value
+    /// outside the bounds it was declared with
+    pub fn set(&mut self, name: &str, value: i64) -> Result<(), String> {
+        match self.values.get_mut(name) {
+            Some((param, _)) if !(param.min..=param.max).contains(&value) => Err(format!(
+                "Parameter \"{}\" must be from {} to {}, got {}",
+                name, param.min, param.max, value
+            )),
+            Some((_, entry)) => {
+                *entry = value;
+                Ok(())
+            }
+            None => Err(format!(
+                "Unknown parameter \"{}\", expected one of: {}",
+                name,
+                self.names()
+            )),
+        }
+    }
+
+    pub fn get<T: TryFrom<i64>>(&self, name: &str) -> Result<T, String> {
+        let (_, value) = *self
+            .values
+            .get(name)
+            .ok_or(format!("Parameter \"{}\" is not declared", name))?;
+
+        T::try_from(value).map_err(|_| format!("Parameter \"{}\" is out of range: {}", name, value))
+    }
+
+    fn names(&self) -> String {
+        if self.values.is_empty() {
+            return "(none)".to_string();
+        }
+        self.values.keys().cloned().collect::<Vec<_>>().join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECLARED: &[Param] = &[
+        Param::new("connections", 1000),
+        Param::new("digits", 2).between(1, 19),
+    ];
+
+    #[test]
+    fn defaults_until_overridden() {
+        let mut params = Params::new(DECLARED);
+        assert_eq!(params.get::<usize>("connections"), Ok(1000));
+
+        params.set("connections", 10).unwrap();
+        assert_eq!(params.get::<usize>("connections"), Ok(10));
+    }
+
+    #[test]
+    fn rejects_undeclared_names() {
+        let mut params = Params::new(DECLARED);
+        assert!(params.set("conections", 10).is_err());
+        assert!(params.get::<usize>("digit").is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_range_values() {
+        let mut params = Params::new(DECLARED);
+        params.set("connections", -1).unwrap();
+        assert!(params.get::<usize>("connections").is_err());
+    }
+
+    #[test]
+    fn rejects_values_out_of_bounds() {
+        let mut params = Params::new(DECLARED);
+        assert_eq!(
+            params.set("digits", 0),
+            Err("Parameter \"digits\" must be from 1 to 19, got 0".to_string())
+        );
+        assert_eq!(
+            params.set("digits", 20),
+            Err("Parameter \"digits\" must be from 1 to 19, got 20".to_string())
+        );
+        assert_eq!(params.get::<u32>("digits"), Ok(2));
+
+        params.set("digits", 19).unwrap();
+        assert_eq!(params.get::<u32>("digits"), Ok(19));
+    }
+}
@@ -0,0 +1,216 @@
+//! Helpers for the shapes puzzle inputs come in. Each takes the whole input
+//! as `source` along with the part being parsed, so that errors point at the
+//! right line, and hands back slices of the input rather than copies
+
+use std::{ops::RangeInclusive, str::FromStr};
+
+use crate::parse_error::{ParseError, parse_token};
+
+/// Exactly `N` values separated by `separator`, such as `162,817,812`
+pub fn tuple<T: FromStr, const N: usize>(
+    source: &str,
+    s: &str,
+    separator: char,
+    expected: &str,
+) -> Result<[T; N], ParseError> {
+    let shape = || {
+        let expected = format!("{} values separated by \"{}\"", N, separator);
+        ParseError::at(source, s, expected)
+    };
+
+    let mut parts = s.split(separator);
+    let mut values = Vec::with_capacity(N);
+    for _ in 0..N {
+        let part = parts.next().ok_or_else(shape)?;
+        values.push(parse_token(source, part.trim(), expected)?);
+    }
+    if parts.next().is_some() {
+        return Err(shape());
+    }
+
+    match values.try_into() {
+        Ok(values) => Ok(values),
+        Err(_) => unreachable!("exactly N values were parsed"),
+    }
+}
+
+/// The two ends of a range written as `a-b`, left unparsed
+pub fn split_range<'a>(source: &str, s: &'a str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once('-')
+        .map(|(start, end)| (start.trim(), end.trim()))
+        .ok_or_else(|| ParseError::at(source, s, "a range such as \"3-5\""))
+}
+
+/// An inclusive range written as `a-b`
+pub fn range<T: FromStr>(
+    source: &str,
+    s: &str,
+    expected: &str,
+) -> Result<RangeInclusive<T>, ParseError> {
+    let (start, end) = split_range(source, s)?;
+    Ok(parse_token(source, start, expected)?..=parse_token(source, end, expected)?)
+}
+
+/// Exactly `N` sections separated by blank lines
+pub fn sections<const N: usize>(s: &str) -> Result<[&str; N], ParseError> {
+    let mut sections = Vec::new();
+    let mut start = None;
+    let mut offset = 0;
+    for line in s.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                sections.push(&s[start..offset]);
+            }
+        } else if start.is_none() {
+            start = Some(offset);
+        }
+        offset += line.len();
+    }
+    if let Some(start) = start {
+        sections.push(&s[start..]);
+    }
+
+    let found = sections.len();
+    sections
+        .into_iter()
+        .map(|s| s.trim_end_matches(['\r', '\n']))
+        .collect::<Vec<_>>()
+        .try_into()
+        .map_err(|_| {
+            ParseError::new(format!(
+                "Expected {} sections separated by blank lines, found {}",
+                N, found
+            ))
+        })
+}
+
+/// The cells of a rectangular grid of characters row by row, along with its
+/// width taken from the first line. `cell` turns a character into a cell or
+/// rejects it
+pub fn char_grid<T>(
+    source: &str,
+    s: &str,
+    cell: impl Fn(char) -> Option<T>,
+    expected: &str,
+) -> Result<(Vec<T>, usize), ParseError> {
+    let width = s.lines().next().ok_or("Input is empty")?.chars().count();
+
+    let mut cells = Vec::new();
+    for line in s.lines() {
+        for (i, c) in line.char_indices() {
+            let token = &line[i..i + c.len_utf8()];
+            cells.push(cell(c).ok_or_else(|| ParseError::at(source, token, expected))?);
+        }
+        if line.chars().count() != width {
+            return Err(ParseError::at(
+                source,
+                line,
+                format!("a row of {} cells", width),
+            ));
+        }
+    }
+
+    Ok((cells, width))
+}
+
+/// Split whitespace-aligned lines into blocks of columns, a block ending at
+/// a column that is blank on every line. Each block holds a slice of every
+/// line, shorter than the block on lines that end early
+pub fn aligned_columns<'a>(lines: &[&'a str]) -> Vec<Vec<&'a str>> {
+    let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+    let blank = |col: usize| {
+        lines
+            .iter()
+            .all(|l| l.as_bytes().get(col).is_none_or(u8::is_ascii_whitespace))
+    };
+
+    let mut blocks = Vec::new();
+    let mut start = None;
+    for col in 0..=width {
+        match (start, col == width || blank(col)) {
+            (None, false) => start = Some(col),
+            (Some(block_start), true) => {
+                start = None;
+                let slice = |l: &&'a str| {
+                    let end = col.min(l.len());
+                    &l[block_start.min(end)..end]
+                };
+                blocks.push(lines.iter().map(slice).collect());
+            }
+            _ => {}
+        }
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tuples() {
+        let input = "162,817,812\n57,618";
+        let [x, y, z] = tuple::<i64, 3>(input, &input[..11], ',', "a coordinate").unwrap();
+        assert_eq!((x, y, z), (162, 817, 812));
+
+        let err = tuple::<i64, 3>(input, &input[12..], ',', "a coordinate").unwrap_err();
+        assert_eq!(err.expected(), Some("3 values separated by \",\""));
+        assert_eq!(err.span().unwrap().line, 2);
+    }
+
+    #[test]
+    fn parses_ranges() {
+        let input = "3-5\n10-x";
+        assert_eq!(range::<u64>(input, &input[..3], "an ID"), Ok(3..=5));
+        assert_eq!(split_range(input, &input[4..]), Ok(("10", "x")));
+
+        let err = range::<u64>(input, &input[4..], "an ID").unwrap_err();
+        assert_eq!(err.span().unwrap().columns, 3..4);
+    }
+
+    #[test]
+    fn splits_sections() {
+        assert_eq!(
+            sections::<2>("3-5\n10-14\n\n1\n5\n"),
+            Ok(["3-5\n10-14", "1\n5"])
+        );
+        assert_eq!(sections::<2>("a\r\n\r\n\r\nb"), Ok(["a", "b"]));
+        assert!(sections::<2>("a\nb\n").is_err());
+    }
+
+    #[test]
+    fn parses_char_grids() {
+        let cell = |c| match c {
+            '.' => Some(false),
+            '@' => Some(true),
+            _ => None,
+        };
+        let (cells, width) = char_grid("..@\n@@.\n", "..@\n@@.\n", cell, "a cell").unwrap();
+        assert_eq!(width, 3);
+        assert_eq!(cells, [false, false, true, true, true, false]);
+
+        let input = "..@\n@.\n";
+        let err = char_grid(input, input, cell, "a cell").unwrap_err();
+        assert_eq!(err.expected(), Some("a row of 3 cells"));
+    }
+
+    #[test]
+    fn splits_aligned_columns() {
+        let lines = [
+            "123 328  51 64 ",
+            " 45 64  387 23 ",
+            "  6 98  215 314",
+            "*   +   *   +  ",
+        ];
+        let blocks = aligned_columns(&lines);
+        assert_eq!(blocks.len(), 4);
+        assert_eq!(blocks[0], ["123", " 45", "  6", "*  "]);
+        assert_eq!(blocks[3], ["64 ", "23 ", "314", "+  "]);
+    }
+
+    #[test]
+    fn aligned_columns_allow_short_lines() {
+        let blocks = aligned_columns(&["12  3", " 4 45", "+  *"]);
+        assert_eq!(blocks, [vec!["12", " 4", "+ "], vec![" 3", "45", "*"]]);
+    }
+}
@@ -0,0 +1,177 @@
+use std::{fmt::Display, ops::Range, str::FromStr};
+
+/// Why an input could not be parsed. Errors about a particular token carry
+/// the line and columns it was found at and render that line with a caret
+/// under the token
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    expected: Option<String>,
+    span: Option<Span>,
+}
+
+/// Where in the input a parse error happened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    /// Line number, starting from 1
+    pub line: usize,
+    /// Columns of the offending token in characters, starting from 0
+    pub columns: Range<usize>,
+    /// The whole line the token is on
+    pub text: String,
+}
+
+impl ParseError {
+    /// An error about the input as a whole rather than a particular token
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            expected: None,
+            span: None,
+        }
+    }
+
+    /// An error at `token`, which must be a slice of `source`. Lines are
+    /// counted from the start of `source` so pass the whole input. A missing
+    /// token can be pointed at with an empty slice where it should have been
+    pub fn at(source: &str, token: &str, expected: impl Into<String>) -> Self {
+        let expected = expected.into();
+        let message = if token.is_empty() {
+            format!("expected {}", expected)
+        } else {
+            format!("expected {}, found \"{}\"", expected, token)
+        };
+
+        Self {
+            message,
+            expected: Some(expected),
+            span: Span::of(source, token),
+        }
+    }
+
+    pub fn expected(&self) -> Option<&str> {
+        self.expected.as_deref()
+    }
+
+    pub fn span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
+}
+
+impl Span {
+    fn of(source: &str, token: &str) -> Option<Self> {
+        let offset = (token.as_ptr() as usize).checked_sub(source.as_ptr() as usize)?;
+        if offset + token.len() > source.len() || !source.is_char_boundary(offset) {
+            return None;
+        }
+
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |i| offset + i);
+        let line = source[..offset].matches('\n').count() + 1;
+
+        // A token running over the end of its line is cut off there
+        let token_end = (offset + token.len()).min(line_end);
+        let start = source[line_start..offset].chars().count();
+        let end = start + source[offset..token_end].chars().count();
+
+        Some(Self {
+            line,
+            columns: start..end,
+            text: source[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        })
+    }
+}
+
+/// Parse `token`, a slice of `source`, reporting where it is on failure
+pub fn parse_token<T: FromStr>(source: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(source, token, expected))
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(span) = &self.span else {
+            return f.write_str(&self.message);
+        };
+
+        let gutter = " ".repeat(span.line.to_string().len());
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            span.line,
+            span.columns.start + 1,
+            self.message
+        )?;
+        writeln!(f, "{} | {}", span.line, span.text)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(span.columns.start),
+            "^".repeat(span.columns.len().max(1))
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<String> for ParseError {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<&str> for ParseError {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_the_line_with_a_caret() {
+        let input = "3-5\n10-1x\n";
+        let token = &input[7..9];
+        let err = ParseError::at(input, token, "a number");
+
+        assert_eq!(err.expected(), Some("a number"));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 4: expected a number, found \"1x\"\n2 | 10-1x\n  |    ^^"
+        );
+    }
+
+    #[test]
+    fn points_at_missing_tokens() {
+        let input = "L68\n5";
+        let err = ParseError::at(input, &input[4..4], "a direction");
+        let span = err.span().unwrap();
+
+        assert_eq!((span.line, span.columns.clone()), (2, 0..0));
+        assert!(err.to_string().ends_with("2 | 5\n  | ^"));
+    }
+
+    #[test]
+    fn tokens_outside_the_source_have_no_span() {
+        let token = String::from("x");
+        let err = ParseError::at("1,2,3", &token, "a number");
+        assert_eq!(err.span(), None);
+        assert_eq!(err.to_string(), "expected a number, found \"x\"");
+    }
+
+    #[test]
+    fn parses_tokens() {
+        let input = "1,x";
+        assert_eq!(parse_token::<u8>(input, &input[..1], "a number"), Ok(1));
+        let err = parse_token::<u8>(input, &input[2..], "a number").unwrap_err();
+        assert_eq!(err.span().unwrap().columns, 2..3);
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    io::{IsTerminal, Write},
+    time::{Duration, Instant},
+};
+
+/// Minimum time between redraws, so counting in a hot loop stays cheap
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A counter for a long-running loop, drawn on a single stderr line with its
+/// rate and estimated time remaining. It only draws when stderr is a
+/// terminal, and never in quiet mode or on CI, so answers on stdout stay clean
+#[derive(Debug)]
+pub struct Progress {
+    label: String,
+    total: u64,
+    count: u64,
+    start: Instant,
+    last_draw: Option<Instant>,
+    enabled: bool,
+}
+
+impl Progress {
+    pub(crate) fn new(label: &str, total: u64, quiet: bool) -> Self {
+        let on_ci = std::env::var_os("CI").is_some();
+        Self {
+            label: label.to_string(),
+            total,
+            count: 0,
+            start: Instant::now(),
+            last_draw: None,
+            enabled: !quiet && !on_ci && std::io::stderr().is_terminal(),
+        }
+    }
+
+    pub fn tick(&mut self) {
+        self.inc(1);
+    }
+
+    pub fn inc(&mut self, n: u64) {
+        self.count += n;
+        if !self.enabled {
+            return;
+        }
+
+        let now = Instant::now();
+        if self
+            .last_draw
+            .is_some_and(|last| now - last < REDRAW_INTERVAL)
+        {
+            return;
+        }
+        self.last_draw = Some(now);
+
+        let line = render(&self.label, self.count, self.total, now - self.start);
+        let mut stderr = std::io::stderr().lock();
+        let _ = write!(stderr, "\r\x1b[K{}", line);
+        let _ = stderr.flush();
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if self.last_draw.is_some() {
+            eprint!("\r\x1b[K");
+        }
+    }
+}
+
+/// A total of zero means the total is unknown, so only the count and rate
+/// are shown
+fn render(label: &str, count: u64, total: u64, elapsed: Duration) -> String {
+    let rate = count as f64 / elapsed.as_secs_f64().max(f64::EPSILON);
+    if total == 0 {
+        return format!("{}: {} ({:.0}/s)", label, count, rate);
+    }
+
+    let remaining = total.saturating_sub(count) as f64 / rate.max(f64::EPSILON);
+    format!(
+        "{}: {}/{} ({:.0}/s, ETA {})",
+        label,
+        count,
+        total,
+        rate,
+        format_eta(remaining)
+    )
+}
+
+fn format_eta(secs: f64) -> String {
+    let secs = secs.min(u32::MAX as f64).round() as u64;
+    match secs {
+        0..60 => format!("{}s", secs),
+        60..3600 => format!("{}m{:02}s", secs / 60, secs % 60),
+        _ => format!("{}h{:02}m", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_rate_and_eta() {
+        assert_eq!(
+            render("areas checked", 50, 200, Duration::from_secs(10)),
+            "areas checked: 50/200 (5/s, ETA 30s)"
+        );
+        assert_eq!(
+            render("rows", 10, 0, Duration::from_secs(2)),
+            "rows: 10 (5/s)"
+        );
+    }
+
+    #[test]
+    fn formats_eta() {
+        assert_eq!(format_eta(42.4), "42s");
+        assert_eq!(format_eta(185.0), "3m05s");
+        assert_eq!(format_eta(7500.0), "2h05m");
+    }
+}
@@ -0,0 +1,173 @@
+use std::{
+    io::{BufRead, Write},
+    str::FromStr,
+};
+
+use crate::{
+    context::Context,
+    solution::{PartResult, Solution},
+};
+
+/// The text a command prints, or the reason it failed
+pub type CommandResult = Result<String, String>;
+
+/// A day-specific command of `--repl` for exploring the parsed input, such
+/// as looking up one entry or stepping a simulation. Registered in
+/// `Solution::COMMANDS`
+pub struct ReplCommand<S: Solution + ?Sized> {
+    pub name: &'static str,
+    /// Arguments the command takes, shown by `help`
+    pub usage: &'static str,
+    pub help: &'static str,
+    /// Runs the command on its whitespace separated arguments, returning the
+    /// text to print
+    pub run: fn(&S::Input, &Context, &[&str]) -> CommandResult,
+}
+
+impl<S: Solution + ?Sized> ReplCommand<S> {
+    pub const fn new(
+        name: &'static str,
+        usage: &'static str,
+        help: &'static str,
+        run: fn(&S::Input, &Context, &[&str]) -> CommandResult,
+    ) -> Self {
+        Self {
+            name,
+            usage,
+            help,
+            run,
+        }
+    }
+}
+
+/// Parse the argument at `index`, naming it after `what` when it is missing
+/// or invalid
+pub fn arg<T: FromStr>(args: &[&str], index: usize, what: &str) -> Result<T, String>
+where
+    T::Err: std::fmt::Display,
+{
+    let arg = args.get(index).ok_or(format!("Expected {}", what))?;
+    arg.parse()
+        .map_err(|e| format!("Expected {}, got \"{}\": {}", what, arg, e))
+}
+
+/// Pretty print a value for a command's result
+pub fn pretty(value: &impl std::fmt::Debug) -> String {
+    format!("{:#?}", value)
+}
+
+/// Read commands from stdin and run them on the parsed input until `quit`
+/// or the end of input
+pub fn repl<S: Solution>(input: &S::Input, ctx: &Context) {
+    let mut stdin = std::io::stdin().lock();
+    println!("Day {} input loaded, \"help\" lists the commands", S::DAY);
+
+    loop {
+        print!("day{}> ", S::DAY);
+        let _ = std::io::stdout().flush();
+
+        let mut line = String::new();
+        match stdin.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(err) => {
+                eprintln!("Could not read the command: {}", err);
+                break;
+            }
+        }
+
+        match execute::<S>(input, ctx, &line) {
+            Some(Ok(output)) if output.is_empty() => {}
+            Some(Ok(output)) => println!("{}", output.trim_end()),
+            Some(Err(err)) => println!("Error: {}", err),
+            None => break,
+        }
+    }
+}
+
+/// Run one line of input, or `None` when it asks to quit
+fn execute<S: Solution>(input: &S::Input, ctx: &Context, line: &str) -> Option<CommandResult> {
+    let words = line.split_whitespace().collect::<Vec<_>>();
+    let Some((name, args)) = words.split_first() else {
+        return Some(Ok(String::new()));
+    };
+
+    let result = match *name {
+        "quit" | "exit" => return None,
+        "help" => Ok(help::<S>()),
+        "part1" => part(S::part1(input, ctx)),
+        "part2" => part(S::part2(input, ctx)),
+        name => match S::COMMANDS.iter().find(|c| c.name == name) {
+            Some(command) => (command.run)(input, ctx, args),
+            None => Err(format!("Unknown command \"{}\", try \"help\"", name)),
+        },
+    };
+    Some(result)
+}
+
+fn part(result: PartResult) -> CommandResult {
+    result.map(|answer| answer.to_string())
+}
+
+fn help<S: Solution>() -> String {
+    let builtin = [
+        ("part1", "", "Solve part 1"),
+        ("part2", "", "Solve part 2"),
+        ("help", "", "List the commands"),
+        ("quit", "", "Leave the REPL"),
+    ];
+    let commands = S::COMMANDS
+        .iter()
+        .map(|c| (c.name, c.usage, c.help))
+        .chain(builtin)
+        .map(|(name, usage, help)| (format!("{} {}", name, usage).trim_end().to_string(), help))
+        .collect::<Vec<_>>();
+
+    let width = commands.iter().map(|(c, _)| c.len()).max().unwrap_or(0);
+    commands
+        .iter()
+        .map(|(command, help)| format!("  {:<width$}  {}\n", command, help, width = width))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{params::Params, test_support::Numbers};
+
+    fn run(line: &str) -> Option<CommandResult> {
+        let ctx = Context::new(Params::new(&[]));
+        execute::<Numbers>(&vec![4, 5, 6], &ctx, line)
+    }
+
+    #[test]
+    fn runs_commands() {
+        assert_eq!(run("get 1\n"), Some(Ok("5".to_string())));
+        assert_eq!(run("  part1 "), Some(Ok("15".to_string())));
+        assert_eq!(run("part2"), Some(Ok("15".to_string())));
+        assert_eq!(run(""), Some(Ok(String::new())));
+        assert_eq!(run("quit"), None);
+        assert!(run("help").unwrap().unwrap().contains("get INDEX"));
+    }
+
+    #[test]
+    fn reports_bad_commands() {
+        assert_eq!(
+            run("frobnicate"),
+            Some(Err(
+                "Unknown command \"frobnicate\", try \"help\"".to_string()
+            ))
+        );
+        assert_eq!(run("get"), Some(Err("Expected an index".to_string())));
+        assert_eq!(
+            run("get x"),
+            Some(Err(
+                "Expected an index, got \"x\": invalid digit found in string".to_string()
+            ))
+        );
+        assert_eq!(
+            run("get 9"),
+            Some(Err("There are only 3 numbers".to_string()))
+        );
+    }
+}
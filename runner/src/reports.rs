@@ -0,0 +1,30 @@
+use std::path::PathBuf;
+
+const REPORTS_PATH: &str = "../reports";
+
+/// Directory holding the generated reports for a day, such as benchmark
+/// results. Like the input cache this is relative to the day's crate
+pub fn report_dir(day: usize) -> PathBuf {
+    PathBuf::from(REPORTS_PATH).join(format!("day{}", day))
+}
+
+/// Write a report file for the day, creating the directory if needed
+pub fn write_report(
+    day: usize,
+    file_name: &str,
+    contents: impl AsRef<[u8]>,
+) -> Result<PathBuf, String> {
+    let dir = report_dir(day);
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Could not create \"{}\": {}", dir.display(), e))?;
+
+    let path = dir.join(file_name);
+    std::fs::write(&path, contents)
+        .map_err(|e| format!("Could not write \"{}\": {}", path.display(), e))?;
+
+    Ok(path)
+}
+
+pub fn read_report(day: usize, file_name: &str) -> Option<String> {
+    std::fs::read_to_string(report_dir(day).join(file_name)).ok()
+}
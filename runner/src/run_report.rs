@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{alloc::AllocStats, answer::Answer};
+
+/// Everything a run of a day produced, printed as JSON with `--json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunReport {
+    pub day: usize,
+    pub parse: PhaseReport,
+    pub part1: PartReport,
+    pub part2: PartReport,
+}
+
+/// Time taken by a phase and, when counted, its allocations
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PhaseReport {
+    pub elapsed_ns: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<AllocStats>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PartReport {
+    #[serde(flatten)]
+    pub phase: PhaseReport,
+    pub answer: Option<Answer>,
+    pub error: Option<String>,
+    /// Whether the part was stopped for exceeding its time budget
+    #[serde(default)]
+    pub timed_out: bool,
+}
@@ -0,0 +1,287 @@
+use std::{
+    any::Any,
+    io::Read,
+    panic::{self, AssertUnwindSafe},
+    time::Instant,
+};
+
+use clap::{CommandFactory, FromArgMatches, error::ErrorKind};
+use get_input::get_profile_input;
+
+use crate::{
+    alloc::{self, format_bytes, measure_allocations},
+    artifacts::ArtifactStore,
+    bench::{bench, format_ns},
+    budget::with_budget,
+    cli::Args,
+    context::Context,
+    cpu_profile::{self, CpuProfiler},
+    generate::generate_input,
+    image::Recording,
+    params::Params,
+    repl::repl,
+    run_report::{PartReport, PhaseReport, RunReport},
+    solution::{PartResult, Solution},
+    variants::select_variants,
+    verbosity::init_logging,
+};
+
+/// Fetch the day's input and print the answers to both parts, or benchmark
+/// the solution or explore its input when asked to
+pub fn run<S: Solution>() {
+    let mut command = Args::command().bin_name(format!("day{}", S::DAY));
+    let args = match Args::from_arg_matches(&command.get_matches_mut()) {
+        Ok(args) => args,
+        Err(err) => err.exit(),
+    };
+
+    init_logging(args.verbose, args.quiet);
+
+    if args.cpu_profile && !cpu_profile::AVAILABLE {
+        let message = "--cpu-profile needs a build with `--features runner/cpu-profile`";
+        command.error(ErrorKind::InvalidValue, message).exit();
+    }
+    if args.alloc && !alloc::AVAILABLE {
+        let message = "--alloc needs a build with `--features runner/count-allocations`";
+        command.error(ErrorKind::InvalidValue, message).exit();
+    }
+
+    let mut params = Params::new(S::PARAMS);
+    for (name, value) in &args.params {
+        if let Err(err) = params.set(name, *value) {
+            command.error(ErrorKind::InvalidValue, err).exit();
+        }
+    }
+    let [variant1, variant2] = match select_variants::<S>(&args.variants) {
+        Ok(selected) => selected,
+        Err(err) => command.error(ErrorKind::InvalidValue, err).exit(),
+    };
+
+    if args.repl && args.input.as_deref() == Some("-") {
+        let message = "--repl reads commands from stdin so cannot read the input from it";
+        command.error(ErrorKind::ArgumentConflict, message).exit();
+    }
+    let input = match load_input::<S>(&args) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Could not get input: {}", err);
+            std::process::exit(1);
+        }
+    };
+    let mut ctx = Context::new(params).with_progress(!args.quiet);
+    // Generated inputs are rarely seen twice so their artifacts are not kept
+    if args.generate.is_none() {
+        ctx = ctx.with_artifacts(ArtifactStore::new(S::DAY, &input));
+    }
+    let recording = args.record.then(Recording::default);
+    if let Some(recording) = &recording {
+        ctx = ctx.with_recording(recording.clone());
+    }
+
+    if args.bench {
+        match bench::<S>(&input, &ctx, &args.bench_options()) {
+            Ok(false) => return,
+            Ok(true) => std::process::exit(1),
+            Err(err) => panic!("Could not complete benchmark: {}", err),
+        }
+    }
+
+    let (input, parse) = run_phase(args.alloc, || S::parse(&input));
+    let input = match input {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Could not parse input: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    if args.repl {
+        repl::<S>(&input, &ctx);
+        return;
+    }
+
+    let part1 = profiled(&args, S::DAY, "part1", || {
+        run_part(&args, &ctx, |ctx| match variant1 {
+            Some(variant) => (variant.solve)(&input, ctx),
+            None => S::part1(&input, ctx),
+        })
+    });
+    save_recording(recording.as_ref(), S::DAY, "part1");
+    if !args.json {
+        print_part(1, variant1.map(|v| v.name), &part1);
+    }
+
+    let part2 = profiled(&args, S::DAY, "part2", || {
+        run_part(&args, &ctx, |ctx| match variant2 {
+            Some(variant) => (variant.solve)(&input, ctx),
+            None => S::part2(&input, ctx),
+        })
+    });
+    save_recording(recording.as_ref(), S::DAY, "part2");
+    if !args.json {
+        print_part(2, variant2.map(|v| v.name), &part2);
+    }
+
+    let report = RunReport {
+        day: S::DAY,
+        parse,
+        part1,
+        part2,
+    };
+
+    if args.json {
+        match serde_json::to_string(&report) {
+            Ok(json) => println!("{}", json),
+            Err(err) => panic!("Could not serialize the report: {}", err),
+        }
+    } else if args.alloc {
+        print_usage(&report);
+    }
+}
+
+/// Generate the input or read it from the file or stdin given on the command
+/// line, falling back to the profile's cached or downloaded puzzle input
+fn load_input<S: Solution>(args: &Args) -> Result<String, String> {
+    if let Some(seed) = args.generate {
+        return generate_input::<S>(seed, args.size)
+            .ok_or(format!("Day {} has no input generator", S::DAY));
+    }
+
+    match args.input.as_deref() {
+        Some("-") => {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| format!("Could not read stdin: {}", e))?;
+            Ok(input)
+        }
+        Some(path) => {
+            std::fs::read_to_string(path).map_err(|e| format!("Could not read \"{}\": {}", path, e))
+        }
+        None => get_profile_input(S::DAY, &args.profile),
+    }
+}
+
+/// Turn a panicking part into a failed one so the other part still runs
+fn catch_panic(part: impl FnOnce() -> PartResult) -> PartResult {
+    match panic::catch_unwind(AssertUnwindSafe(part)) {
+        Ok(result) => result,
+        Err(payload) => Err(format!("Panicked: {}", panic_message(payload.as_ref()))),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown cause"
+    }
+}
+
+fn run_phase<T>(count_allocations: bool, f: impl FnOnce() -> T) -> (T, PhaseReport) {
+    let start = Instant::now();
+    let (result, allocations) = if count_allocations {
+        let (result, allocations) = measure_allocations(f);
+        (result, Some(allocations))
+    } else {
+        (f(), None)
+    };
+
+    let report = PhaseReport {
+        elapsed_ns: start.elapsed().as_nanos() as u64,
+        allocations,
+    };
+    (result, report)
+}
+
+/// Save what the part recorded under the phase's name
+fn save_recording(recording: Option<&Recording>, day: usize, phase: &str) {
+    match recording.and_then(|r| r.save(day, phase)) {
+        Some(Ok(message)) => eprintln!("{}", message),
+        Some(Err(err)) => eprintln!("Could not save the {} recording: {}", phase, err),
+        None if recording.is_some() => eprintln!("Nothing was recorded for {}", phase),
+        None => {}
+    }
+}
+
+/// Run a phase under the CPU profiler when asked to, saving its profile
+/// under the phase's name. A profiler that fails only costs the profile
+fn profiled<T>(args: &Args, day: usize, phase: &str, f: impl FnOnce() -> T) -> T {
+    if !args.cpu_profile {
+        return f();
+    }
+
+    let profiler = CpuProfiler::start();
+    let result = f();
+    match profiler.and_then(|p| p.finish(day, phase)) {
+        Ok(paths) => {
+            for path in paths {
+                eprintln!("Profile written to \"{}\"", path.display());
+            }
+        }
+        Err(err) => eprintln!("Could not profile {}: {}", phase, err),
+    }
+    result
+}
+
+/// Run a part within its time budget, if it has one. A part that gives up
+/// after its budget ran out is reported as timed out rather than failed
+fn run_part(args: &Args, ctx: &Context, part: impl FnOnce(&Context) -> PartResult) -> PartReport {
+    let ((result, phase), cancelled) = with_budget(args.budget, |cancellation| {
+        let ctx = ctx.with_cancellation(cancellation.clone());
+        run_phase(args.alloc, || catch_panic(|| part(&ctx)))
+    });
+
+    let timed_out = cancelled && result.is_err();
+    let (answer, error) = match (result, args.budget) {
+        (Ok(answer), _) => (Some(answer), None),
+        (Err(_), Some(budget)) if timed_out => (
+            None,
+            Some(format!("Timed out after its budget of {:?}", budget)),
+        ),
+        (Err(err), _) => (None, Some(err)),
+    };
+    PartReport {
+        phase,
+        answer,
+        error,
+        timed_out,
+    }
+}
+
+fn print_part(part: usize, variant: Option<&str>, report: &PartReport) {
+    let part = match variant {
+        Some(variant) => format!("{} ({})", part, variant),
+        None => part.to_string(),
+    };
+    match (&report.answer, &report.error) {
+        (Some(answer), _) => println!("Part {}: {}", part, answer),
+        (None, Some(err)) => println!("Could not complete part {}: {}", part, err),
+        (None, None) => {}
+    }
+}
+
+fn print_usage(report: &RunReport) {
+    println!(
+        "{:<8}{:>12}{:>14}{:>12}{:>12}",
+        "phase", "time", "allocations", "allocated", "peak"
+    );
+    let phases = [
+        ("parse", &report.parse),
+        ("part1", &report.part1.phase),
+        ("part2", &report.part2.phase),
+    ];
+    for (name, phase) in phases {
+        let a = phase.allocations.unwrap_or_default();
+        println!(
+            "{:<8}{:>12}{:>14}{:>12}{:>12}",
+            name,
+            format_ns(phase.elapsed_ns),
+            a.allocations,
+            format_bytes(a.bytes),
+            format_bytes(a.peak_bytes)
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{
+    answer::Answer, context::Context, generate::InputRng, params::Param, parse_error::ParseError,
+    repl::ReplCommand, variants::Variant,
+};
+
+/// The answer to a part, or the reason it could not be completed
+pub type PartResult = Result<Answer, String>;
+
+/// A day's puzzle solution. The runner takes care of fetching the input,
+/// handing it to `parse` once and running both parts over the result
+pub trait Solution: 'static {
+    /// Puzzle day, used to locate the input and the example fixtures
+    const DAY: usize;
+
+    /// Named constants that differ between the examples and the real input,
+    /// read back in the parts through `Context::param`
+    const PARAMS: &'static [Param] = &[];
+
+    /// Other ways of solving the parts, checked against `part1` and `part2`
+    /// on generated inputs by `check_variants`
+    const VARIANTS: &'static [Variant<Self>] = &[];
+
+    /// Commands for exploring the parsed input with `--repl`
+    const COMMANDS: &'static [ReplCommand<Self>] = &[];
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input, ctx: &Context) -> PartResult;
+
+    fn part2(input: &Self::Input, ctx: &Context) -> PartResult;
+
+    /// A random, structurally valid input whose size grows with `size`, for
+    /// stress tests and benchmarks beyond the real input. Days that cannot
+    /// generate inputs keep the default of `None`
+    fn generate(_rng: &mut InputRng, _size: usize) -> Option<String> {
+        None
+    }
+}
@@ -0,0 +1,65 @@
+//! A fake day shared by the unit tests
+
+use rand::Rng;
+
+use crate::{
+    context::Context,
+    generate::InputRng,
+    parse_error::ParseError,
+    repl::{self, ReplCommand},
+    solution::{PartResult, Solution},
+    variants::Variant,
+};
+
+/// Sums a list of numbers in both parts, with a variant of each part where
+/// only the first agrees and a command looking a number up
+pub(crate) struct Numbers;
+
+impl Solution for Numbers {
+    const DAY: usize = 0;
+
+    const VARIANTS: &'static [Variant<Self>] = &[
+        Variant::<Self>::part1("same", |input, _| Ok(input.iter().sum::<i64>().into())),
+        Variant::<Self>::part2("all-but-last", |input, _| {
+            Ok(input.iter().rev().skip(1).sum::<i64>().into())
+        }),
+    ];
+
+    const COMMANDS: &'static [ReplCommand<Self>] = &[ReplCommand::<Self>::new(
+        "get",
+        "INDEX",
+        "Show the number at INDEX",
+        |input, _, args| {
+            let index: usize = repl::arg(args, 0, "an index")?;
+            input
+                .get(index)
+                .map(repl::pretty)
+                .ok_or(format!("There are only {} numbers", input.len()))
+        },
+    )];
+
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|l| l.parse().map_err(|_| ParseError::new("not a number")))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input, _ctx: &Context) -> PartResult {
+        Ok(input.iter().sum::<i64>().into())
+    }
+
+    fn part2(input: &Self::Input, _ctx: &Context) -> PartResult {
+        Ok(input.iter().sum::<i64>().into())
+    }
+
+    fn generate(rng: &mut InputRng, size: usize) -> Option<String> {
+        Some(
+            (0..size)
+                .map(|_| format!("{}\n", rng.random_range(0..100)))
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,198 @@
+use proptest::{
+    prelude::{Strategy, any},
+    test_runner::{Config, TestCaseError, TestError, TestRunner},
+};
+
+use crate::{
+    context::Context,
+    generate::generate_input,
+    params::Params,
+    solution::{PartResult, Solution},
+};
+
+/// Generated inputs tried by `check_variants`
+const CASES: u32 = 32;
+/// Largest size of the generated inputs. Variants are usually brute forces
+/// so the inputs are kept small
+const MAX_SIZE: usize = 10;
+
+/// Another way of solving one of a day's parts, such as the brute force an
+/// optimised part replaced. Registered in `Solution::VARIANTS` and chosen
+/// over the part with `--variant`. Names are used on the command line and
+/// in benchmark results so are kept short, such as `brute-force`
+pub struct Variant<S: Solution + ?Sized> {
+    pub name: &'static str,
+    pub part: usize,
+    pub solve: fn(&S::Input, &Context) -> PartResult,
+}
+
+impl<S: Solution + ?Sized> Variant<S> {
+    pub const fn part1(name: &'static str, solve: fn(&S::Input, &Context) -> PartResult) -> Self {
+        Self {
+            name,
+            part: 1,
+            solve,
+        }
+    }
+
+    pub const fn part2(name: &'static str, solve: fn(&S::Input, &Context) -> PartResult) -> Self {
+        Self {
+            name,
+            part: 2,
+            solve,
+        }
+    }
+}
+
+/// The variants chosen on the command line for each part, `None` where the
+/// day's own part is kept. A name chooses the variant of that name for every
+/// part that has one
+pub(crate) fn select_variants<S: Solution>(
+    names: &[String],
+) -> Result<[Option<&'static Variant<S>>; 2], String> {
+    let mut selected = [None, None];
+    for name in names {
+        let mut found = false;
+        for variant in S::VARIANTS.iter().filter(|v| v.name == name) {
+            found = true;
+            let slot: &mut Option<&Variant<S>> = &mut selected[variant.part - 1];
+            match slot {
+                Some(other) if other.name != variant.name => {
+                    return Err(format!(
+                        "Variants \"{}\" and \"{}\" both solve part {}",
+                        other.name, variant.name, variant.part
+                    ));
+                }
+                _ => *slot = Some(variant),
+            }
+        }
+
+        if !found {
+            let names = S::VARIANTS.iter().map(|v| v.name).collect::<Vec<_>>();
+            if names.is_empty() {
+                return Err(format!("Day {} has no variants", S::DAY));
+            }
+            return Err(format!(
+                "Day {} has no variant \"{}\", it has {}",
+                S::DAY,
+                name,
+                names.join(", ")
+            ));
+        }
+    }
+    Ok(selected)
+}
+
+/// Check every variant of the day against the day's own part on generated
+/// inputs, panicking with the smallest input found where they disagree.
+/// Counterexamples shrink towards a smaller size from the same seed, as
+/// another seed gives an unrelated input rather than a smaller one. Days
+/// without variants or without a generator pass trivially
+pub fn check_variants<S: Solution>() {
+    if S::VARIANTS.is_empty() || generate_input::<S>(0, 1).is_none() {
+        return;
+    }
+
+    let config = Config {
+        cases: CASES,
+        failure_persistence: None,
+        ..Config::default()
+    };
+    let inputs = (any::<u64>().no_shrink(), 1..=MAX_SIZE);
+    let result = TestRunner::new(config).run(&inputs, |(seed, size)| {
+        let Some(input) = generate_input::<S>(seed, size) else {
+            return Ok(());
+        };
+        compare_variants::<S>(&input)
+            .map_err(|e| TestCaseError::fail(format!("seed {}, size {}: {}", seed, size, e)))
+    });
+
+    match result {
+        Ok(()) => {}
+        Err(TestError::Fail(reason, (seed, size))) => panic!(
+            "{}\nGenerated input:\n{}",
+            reason,
+            generate_input::<S>(seed, size).unwrap_or_default()
+        ),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// Run every variant over the input, describing the first disagreement with
+/// the day's own part
+fn compare_variants<S: Solution>(input: &str) -> Result<(), String> {
+    let parsed = S::parse(input).map_err(|e| format!("Could not parse input: {}", e))?;
+    let ctx = Context::new(Params::new(S::PARAMS));
+
+    for variant in S::VARIANTS {
+        let expected = match variant.part {
+            1 => S::part1(&parsed, &ctx),
+            _ => S::part2(&parsed, &ctx),
+        };
+        let actual = (variant.solve)(&parsed, &ctx);
+        if !agree(&expected, &actual) {
+            return Err(format!(
+                "variant \"{}\" of part {} gave {:?}, expected {:?}",
+                variant.name, variant.part, actual, expected
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Answers agree when they render the same, failures only when they fail
+/// for the same reason so a broken variant cannot hide behind another
+fn agree(a: &PartResult, b: &PartResult) -> bool {
+    match (a, b) {
+        (Ok(a), Ok(b)) => a.canonical() == b.canonical(),
+        (Err(a), Err(b)) => a == b,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Numbers;
+
+    #[test]
+    fn finds_disagreements() {
+        let input = "1\n2\n";
+        assert_eq!(
+            compare_variants::<Numbers>(input),
+            Err(
+                r#"variant "all-but-last" of part 2 gave Ok(Integer(1)), expected Ok(Integer(3))"#
+                    .to_string()
+            )
+        );
+        assert_eq!(compare_variants::<Numbers>("1\n0\n"), Ok(()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Generated input")]
+    fn panics_with_the_counterexample() {
+        check_variants::<Numbers>();
+    }
+
+    #[test]
+    fn selects_variants_by_name() {
+        let [part1, part2] = select_variants::<Numbers>(&["all-but-last".to_string()]).unwrap();
+        assert!(part1.is_none());
+        assert_eq!(part2.map(|v| v.name), Some("all-but-last"));
+
+        let [part1, _] = select_variants::<Numbers>(&[]).unwrap();
+        assert!(part1.is_none());
+        assert_eq!(
+            select_variants::<Numbers>(&["fast".to_string()]).err(),
+            Some(r#"Day 0 has no variant "fast", it has same, all-but-last"#.to_string())
+        );
+    }
+
+    #[test]
+    fn agreement_ignores_the_answer_type() {
+        assert!(agree(&Ok(3_i64.into()), &Ok(3_u64.into())));
+        assert!(agree(&Err("a".into()), &Err("a".into())));
+        assert!(!agree(&Err("a".into()), &Err("b".into())));
+        assert!(!agree(&Ok(3_i64.into()), &Err("b".into())));
+    }
+}
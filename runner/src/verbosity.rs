@@ -0,0 +1,24 @@
+use std::io::IsTerminal;
+
+use tracing::Level;
+
+/// Send diagnostics from `tracing` macros to stderr, keeping stdout for the
+/// answers. Nothing below info is shown by default, each `-v` lowers that by
+/// one level and `-q` shows errors only
+pub(crate) fn init_logging(verbose: u8, quiet: bool) {
+    let level = match (quiet, verbose) {
+        (true, _) => Level::ERROR,
+        (false, 0) => Level::INFO,
+        (false, 1) => Level::DEBUG,
+        (false, _) => Level::TRACE,
+    };
+
+    // Only fails if a subscriber is already set, in which case keep it
+    let _ = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::stderr().is_terminal())
+        .with_target(false)
+        .without_time()
+        .try_init();
+}